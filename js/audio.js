@@ -1,25 +1,101 @@
-let trackIndex = 0;
-const tracks = [
-  new Audio("resources/dungeon_music_r2.mp3"),
-  new Audio("resources/marching_music.mp3"),
-  new Audio("resources/app_amb1.mp3"),
-  new Audio("resources/apprentice4.mp3"),
-];
-
-export function setupAudio() {
-  tracks.forEach(
+let musicVolume = 1;
+let fadeMs = 1;
+let currentDeck = null;
+let fadingDecks = [];
+
+function createDeck(paths) {
+  const deck = {
+    tracks: paths.map((path) => new Audio(path)),
+    index: 0,
+    level: 0,
+    starting: false,
+  };
+  deck.tracks.forEach(
     (track, index) =>
       (track.onended = () => {
-        trackIndex = index + 1 === tracks.length ? 0 : index + 1;
-        tracks[trackIndex].play();
+        deck.index = index + 1 === deck.tracks.length ? 0 : index + 1;
+        startDeck(deck);
       })
   );
+  return deck;
+}
+
+// browsers refuse to play audio until the page has been interacted with, so
+// a deck that failed to start is retried on the next update
+function startDeck(deck) {
+  deck.starting = true;
+  deck.tracks[deck.index]
+    .play()
+    .catch(() => {})
+    .finally(() => (deck.starting = false));
+}
+
+function applyVolume(deck) {
+  const volume = Math.min(Math.max(deck.level * musicVolume, 0), 1);
+  deck.tracks.forEach((track) => (track.volume = volume));
+}
+
+function stopDeck(deck) {
+  deck.tracks.forEach((track) => {
+    track.onended = null;
+    track.pause();
+  });
 }
 
-export function playAudio() {
-  tracks[trackIndex].play();
+// tracks arrive as a newline separated list
+export function crossfadeMusic(paths, durationMs) {
+  fadeMs = Math.max(durationMs, 1);
+  if (currentDeck) {
+    fadingDecks.push(currentDeck);
+  }
+  const trackPaths = paths.split("\n").filter((path) => path.length > 0);
+  currentDeck = trackPaths.length > 0 ? createDeck(trackPaths) : null;
+  if (currentDeck) {
+    applyVolume(currentDeck);
+    startDeck(currentDeck);
+  }
+}
+
+export function updateMusic(elapsedMs) {
+  const step = elapsedMs / fadeMs;
+  if (currentDeck) {
+    currentDeck.level = Math.min(currentDeck.level + step, 1);
+    applyVolume(currentDeck);
+    if (currentDeck.tracks[currentDeck.index].paused && !currentDeck.starting) {
+      startDeck(currentDeck);
+    }
+  }
+  fadingDecks.forEach((deck) => {
+    deck.level = Math.max(deck.level - step, 0);
+    applyVolume(deck);
+  });
+  fadingDecks.filter((deck) => deck.level <= 0).forEach(stopDeck);
+  fadingDecks = fadingDecks.filter((deck) => deck.level > 0);
+}
+
+export function setMusicVolume(volume) {
+  musicVolume = volume;
+  [currentDeck, ...fadingDecks]
+    .filter((deck) => deck)
+    .forEach(applyVolume);
+}
+
+const soundEffects = {};
+let soundEffectVolume = 1;
+
+export function playSoundEffect(path) {
+  if (soundEffectVolume <= 0) {
+    return;
+  }
+  if (!soundEffects[path]) {
+    soundEffects[path] = new Audio(path);
+  }
+  // clone so the same effect can overlap itself
+  const sound = soundEffects[path].cloneNode();
+  sound.volume = soundEffectVolume;
+  sound.play().catch(() => {});
 }
 
-export function pauseAudio() {
-  tracks[trackIndex].pause();
+export function setSoundEffectVolume(volume) {
+  soundEffectVolume = volume;
 }
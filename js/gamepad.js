@@ -0,0 +1,30 @@
+// the first connected gamepad, buttons and axes follow the standard mapping
+function getGamepad() {
+  const gamepads = navigator.getGamepads ? navigator.getGamepads() : [];
+  return Array.from(gamepads).find((gamepad) => gamepad && gamepad.connected);
+}
+
+// pressed buttons as a bit mask, with bit n set for button n
+export function getGamepadButtons() {
+  const gamepad = getGamepad();
+  if (!gamepad) {
+    return 0;
+  }
+  return gamepad.buttons
+    .slice(0, 16)
+    .reduce(
+      (mask, button, index) => (button.pressed ? mask | (1 << index) : mask),
+      0
+    );
+}
+
+export function getGamepadLeftStickX() {
+  const gamepad = getGamepad();
+  return gamepad ? gamepad.axes[0] : 0;
+}
+
+// flipped so that up is positive, the same as on native
+export function getGamepadLeftStickY() {
+  const gamepad = getGamepad();
+  return gamepad ? -gamepad.axes[1] : 0;
+}
@@ -0,0 +1,46 @@
+mod music_director;
+#[cfg(not(target_arch = "wasm32"))]
+mod rodio_backend;
+#[cfg(not(target_arch = "wasm32"))]
+mod silent_backend;
+mod sound_effect;
+#[cfg(target_arch = "wasm32")]
+mod wasm_backend;
+
+pub use music_director::{get_music_context, MusicDirector};
+#[cfg(not(target_arch = "wasm32"))]
+pub use rodio_backend::RodioBackend;
+#[cfg(not(target_arch = "wasm32"))]
+pub use silent_backend::SilentBackend;
+pub use sound_effect::SoundEffect;
+#[cfg(target_arch = "wasm32")]
+pub use wasm_backend::WasmBackend;
+
+#[derive(PartialEq, Clone, Copy, Debug)]
+pub enum AudioChannel {
+    Music,
+    SoundEffects,
+}
+
+pub trait AudioBackend {
+    // fades out whatever music is playing while fading in the given tracks, which then loop
+    fn crossfade_music(&mut self, tracks: &[String], duration_ms: f32);
+    // called every frame to advance any crossfade
+    fn update(&mut self, elapsed_ms: f32);
+    fn play_sound_effect(&mut self, effect: SoundEffect);
+    fn set_volume(&mut self, channel: AudioChannel, volume: f32);
+}
+
+// without an output device the game still runs, just without sound
+#[cfg(not(target_arch = "wasm32"))]
+pub fn get_audio_backend() -> Box<dyn AudioBackend> {
+    match RodioBackend::new() {
+        Some(backend) => Box::new(backend),
+        None => Box::new(SilentBackend {}),
+    }
+}
+
+#[cfg(target_arch = "wasm32")]
+pub fn get_audio_backend() -> Box<dyn AudioBackend> {
+    Box::new(WasmBackend::new())
+}
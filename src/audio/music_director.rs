@@ -0,0 +1,133 @@
+// Picks what music should be playing from the state of the game and crossfades to it whenever that
+// changes. The mapping of tracks to contexts lives in music.ron.
+use super::AudioBackend;
+use crate::components::CombatStats;
+use crate::inventory;
+use crate::run_state::RunState;
+use ron::from_str;
+use serde::{Deserialize, Serialize};
+use specs::{Entity, World, WorldExt};
+use std::collections::HashMap;
+
+const MUSIC_MAPPING_STRING: &str = include_str!("./music.ron");
+const LOW_HEALTH_RATIO: f32 = 0.25;
+
+#[derive(PartialEq, Eq, Hash, Serialize, Deserialize, Clone, Copy, Debug)]
+pub enum MusicContext {
+    MainMenu,
+    Exploration,
+    MonstersInView,
+    LowHealth,
+    CarryingTalisman,
+    Victory,
+    Defeat,
+}
+
+impl MusicContext {
+    fn is_in_dungeon(&self) -> bool {
+        match self {
+            Self::Exploration | Self::MonstersInView | Self::LowHealth | Self::CarryingTalisman => {
+                true
+            }
+            _ => false,
+        }
+    }
+
+    fn get_fallback(&self) -> Option<Self> {
+        match self {
+            Self::MainMenu => None,
+            Self::Exploration => Some(Self::MainMenu),
+            Self::Victory | Self::Defeat => Some(Self::MainMenu),
+            _ => Some(Self::Exploration),
+        }
+    }
+}
+
+fn player_has_low_health(world: &World) -> bool {
+    let player_ent = world.fetch::<Entity>();
+    let combat_stats = world.read_storage::<CombatStats>();
+    combat_stats.get(*player_ent).map_or(false, |stats| {
+        (stats.hp as f32 / stats.max_hp.max(1) as f32) <= LOW_HEALTH_RATIO
+    })
+}
+
+pub fn get_music_context(
+    run_state: &RunState,
+    world: &World,
+    monsters_in_view: bool,
+) -> MusicContext {
+    match run_state {
+        RunState::MainMenu { .. }
+        | RunState::OptionsScreen { .. }
+        | RunState::SetKey { .. }
+        | RunState::CreditsScreen
+        | RunState::IntroScreen
+        | RunState::LoadingScreen { .. }
+        | RunState::SavingScreen { .. }
+        | RunState::NewGameScreen { .. } => MusicContext::MainMenu,
+        RunState::SuccessScreen => MusicContext::Victory,
+        RunState::DeathScreen | RunState::FailureScreen => MusicContext::Defeat,
+        _ if player_has_low_health(world) => MusicContext::LowHealth,
+        _ if monsters_in_view => MusicContext::MonstersInView,
+        _ if inventory::has_objective_in_backpack(world) => MusicContext::CarryingTalisman,
+        _ => MusicContext::Exploration,
+    }
+}
+
+#[derive(Deserialize)]
+struct MusicMapping {
+    crossfade_ms: f32,
+    min_context_ms: f32,
+    tracks: HashMap<MusicContext, Vec<String>>,
+}
+
+pub struct MusicDirector {
+    mapping: MusicMapping,
+    context: Option<MusicContext>,
+    time_in_context: f32,
+}
+
+impl MusicDirector {
+    pub fn new() -> Self {
+        Self {
+            mapping: from_str(MUSIC_MAPPING_STRING).expect("failed to parse music mapping"),
+            context: None,
+            time_in_context: 0.0,
+        }
+    }
+
+    fn get_tracks(&self, context: MusicContext) -> &[String] {
+        match self.mapping.tracks.get(&context) {
+            Some(tracks) if !tracks.is_empty() => tracks,
+            _ => match context.get_fallback() {
+                Some(fallback) => self.get_tracks(fallback),
+                None => &[],
+            },
+        }
+    }
+
+    pub fn update(&mut self, context: MusicContext, audio: &mut dyn AudioBackend, elapsed_ms: f32) {
+        self.time_in_context += elapsed_ms;
+        audio.update(elapsed_ms);
+        let should_change = match self.context {
+            None => true,
+            Some(current) if current == context => false,
+            Some(current) => {
+                !(current.is_in_dungeon() && context.is_in_dungeon())
+                    || self.time_in_context >= self.mapping.min_context_ms
+            }
+        };
+        if should_change {
+            // contexts sharing the same tracks carry on without restarting
+            let restart = match self.context {
+                Some(current) => self.get_tracks(current) != self.get_tracks(context),
+                None => true,
+            };
+            if restart {
+                audio.crossfade_music(self.get_tracks(context), self.mapping.crossfade_ms);
+            }
+            self.context = Some(context);
+            self.time_in_context = 0.0;
+        }
+    }
+}
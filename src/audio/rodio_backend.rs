@@ -0,0 +1,125 @@
+use super::{AudioBackend, AudioChannel, SoundEffect};
+use rodio::{source::Buffered, Decoder, OutputStream, OutputStreamHandle, Sink, Source};
+use std::{collections::HashMap, fs::File, io::BufReader};
+
+type Sound = Buffered<Decoder<BufReader<File>>>;
+
+fn open_decoder(path: &str) -> Option<Decoder<BufReader<File>>> {
+    let file = File::open(path).ok()?;
+    Decoder::new(BufReader::new(file)).ok()
+}
+
+// missing or unreadable tracks are skipped, with nothing left to play there's no sink at all
+fn create_music_sink(stream_handle: &OutputStreamHandle, tracks: &[String]) -> Option<Sink> {
+    let decoders: Vec<_> = tracks
+        .iter()
+        .filter_map(|path| open_decoder(path))
+        .collect();
+    if decoders.is_empty() {
+        return None;
+    }
+    let sink = Sink::try_new(stream_handle).ok()?;
+    let (queue_input, queue_output) = rodio::queue::queue(false);
+    decoders
+        .into_iter()
+        .for_each(|track| queue_input.append(track));
+    sink.set_volume(0.0);
+    sink.append(queue_output.repeat_infinite());
+    Some(sink)
+}
+
+struct MusicDeck {
+    sink: Sink,
+    // how far faded in the deck is, from 0 to 1
+    level: f32,
+}
+
+pub struct RodioBackend {
+    music: Option<MusicDeck>,
+    // dropping a sink stops it, so these go once they've faded out
+    fading_out: Vec<MusicDeck>,
+    fade_ms: f32,
+    music_volume: f32,
+    sound_effects: HashMap<SoundEffect, Sound>,
+    sound_effect_volume: f32,
+    stream_handle: OutputStreamHandle,
+    // dropping the stream stops all playback
+    _stream: OutputStream,
+}
+
+impl RodioBackend {
+    // missing or unreadable files are skipped rather than stopping the game
+    pub fn new() -> Option<Self> {
+        let (stream, stream_handle) = OutputStream::try_default().ok()?;
+        let sound_effects = SoundEffect::effects()
+            .iter()
+            .filter_map(|effect| {
+                open_decoder(effect.get_file_path()).map(|sound| (*effect, sound.buffered()))
+            })
+            .collect();
+        Some(Self {
+            music: None,
+            fading_out: Vec::new(),
+            fade_ms: 1.0,
+            music_volume: 1.0,
+            sound_effects,
+            sound_effect_volume: 1.0,
+            stream_handle,
+            _stream: stream,
+        })
+    }
+
+    fn apply_music_volume(&mut self) {
+        let volume = self.music_volume;
+        self.music
+            .iter_mut()
+            .chain(self.fading_out.iter_mut())
+            .for_each(|deck| deck.sink.set_volume(deck.level * volume));
+    }
+}
+
+impl AudioBackend for RodioBackend {
+    fn crossfade_music(&mut self, tracks: &[String], duration_ms: f32) {
+        self.fade_ms = duration_ms.max(1.0);
+        if let Some(deck) = self.music.take() {
+            self.fading_out.push(deck);
+        }
+        self.music = create_music_sink(&self.stream_handle, tracks)
+            .map(|sink| MusicDeck { sink, level: 0.0 });
+    }
+
+    fn update(&mut self, elapsed_ms: f32) {
+        let step = elapsed_ms / self.fade_ms;
+        if let Some(deck) = self.music.as_mut() {
+            deck.level = (deck.level + step).min(1.0);
+        }
+        self.fading_out
+            .iter_mut()
+            .for_each(|deck| deck.level = (deck.level - step).max(0.0));
+        self.fading_out.retain(|deck| deck.level > 0.0);
+        self.apply_music_volume();
+    }
+
+    fn play_sound_effect(&mut self, effect: SoundEffect) {
+        if self.sound_effect_volume <= 0.0 {
+            return;
+        }
+        if let Some(sound) = self.sound_effects.get(&effect) {
+            if let Ok(sink) = Sink::try_new(&self.stream_handle) {
+                sink.set_volume(self.sound_effect_volume);
+                sink.append(sound.clone());
+                sink.detach();
+            }
+        }
+    }
+
+    fn set_volume(&mut self, channel: AudioChannel, volume: f32) {
+        match channel {
+            AudioChannel::Music => {
+                self.music_volume = volume;
+                self.apply_music_volume();
+            }
+            AudioChannel::SoundEffects => self.sound_effect_volume = volume,
+        }
+    }
+}
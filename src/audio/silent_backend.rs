@@ -0,0 +1,10 @@
+use super::{AudioBackend, AudioChannel, SoundEffect};
+
+pub struct SilentBackend {}
+
+impl AudioBackend for SilentBackend {
+    fn crossfade_music(&mut self, _tracks: &[String], _duration_ms: f32) {}
+    fn update(&mut self, _elapsed_ms: f32) {}
+    fn play_sound_effect(&mut self, _effect: SoundEffect) {}
+    fn set_volume(&mut self, _channel: AudioChannel, _volume: f32) {}
+}
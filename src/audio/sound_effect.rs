@@ -0,0 +1,30 @@
+#[derive(PartialEq, Eq, Hash, Clone, Copy, Debug)]
+pub enum SoundEffect {
+    Hit,
+    DoorOpen,
+    DoorClose,
+    TrapSnap,
+    Fire,
+}
+
+impl SoundEffect {
+    pub fn effects() -> Box<[Self]> {
+        Box::new([
+            Self::Hit,
+            Self::DoorOpen,
+            Self::DoorClose,
+            Self::TrapSnap,
+            Self::Fire,
+        ])
+    }
+
+    pub fn get_file_path(&self) -> &'static str {
+        match self {
+            Self::Hit => "resources/sfx/hit.wav",
+            Self::DoorOpen => "resources/sfx/door_open.wav",
+            Self::DoorClose => "resources/sfx/door_close.wav",
+            Self::TrapSnap => "resources/sfx/trap_snap.wav",
+            Self::Fire => "resources/sfx/fire.wav",
+        }
+    }
+}
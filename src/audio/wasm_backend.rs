@@ -0,0 +1,40 @@
+use super::{AudioBackend, AudioChannel, SoundEffect};
+use wasm_bindgen::prelude::*;
+
+#[wasm_bindgen(module = "/js/audio.js")]
+extern "C" {
+    fn crossfadeMusic(paths: &str, duration_ms: f32);
+    fn updateMusic(elapsed_ms: f32);
+    fn setMusicVolume(volume: f32);
+    fn playSoundEffect(path: &str);
+    fn setSoundEffectVolume(volume: f32);
+}
+
+pub struct WasmBackend {}
+
+impl WasmBackend {
+    pub fn new() -> Self {
+        Self {}
+    }
+}
+
+impl AudioBackend for WasmBackend {
+    fn crossfade_music(&mut self, tracks: &[String], duration_ms: f32) {
+        crossfadeMusic(&tracks.join("\n"), duration_ms);
+    }
+
+    fn update(&mut self, elapsed_ms: f32) {
+        updateMusic(elapsed_ms);
+    }
+
+    fn play_sound_effect(&mut self, effect: SoundEffect) {
+        playSoundEffect(effect.get_file_path());
+    }
+
+    fn set_volume(&mut self, channel: AudioChannel, volume: f32) {
+        match channel {
+            AudioChannel::Music => setMusicVolume(volume),
+            AudioChannel::SoundEffects => setSoundEffectVolume(volume),
+        }
+    }
+}
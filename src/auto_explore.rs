@@ -0,0 +1,90 @@
+use crate::components::{Door, Position};
+use crate::dungeon::{dungeon::Dungeon, level::Level, level_utils, tile_type::TileType};
+use rltk::{a_star_search, DistanceAlg};
+use serde::{Deserialize, Serialize};
+use specs::{Entity, World, WorldExt};
+
+// what is allowed to cut an auto-explore short, taking damage always does
+#[derive(Clone, Copy, Debug, Serialize, Deserialize)]
+pub struct ExploreInterrupts {
+    pub on_monster: bool,
+    pub on_item: bool,
+}
+
+impl Default for ExploreInterrupts {
+    fn default() -> Self {
+        Self {
+            on_monster: true,
+            on_item: true,
+        }
+    }
+}
+
+pub enum AutoExploreStep {
+    Move(usize),
+    OpenDoor(Entity),
+    Blocked,
+    Done,
+}
+
+fn is_explorable(level: &Level, idx: usize) -> bool {
+    level.revealed_tiles[idx]
+        && level_utils::is_exit_valid(level, idx)
+        && (!level.blocked[idx] || level.tiles[idx] == TileType::Door)
+}
+
+fn borders_unrevealed_tile(level: &Level, idx: usize) -> bool {
+    level_utils::get_neighbors_for_idx(level.width as i32, idx as i32)
+        .iter()
+        .any(|neighbor| {
+            *neighbor >= 0
+                && !level_utils::idx_not_in_map(level, *neighbor as usize)
+                && !level.revealed_tiles[*neighbor as usize]
+        })
+}
+
+fn get_frontier_tiles(level: &Level, start_idx: usize) -> Vec<usize> {
+    let reachable = level_utils::get_connected_tiles(level, start_idx, |idx| {
+        idx == start_idx || is_explorable(level, idx)
+    });
+    let start = level_utils::idx_point(level.width as u32, start_idx);
+    let mut frontier: Vec<(usize, f32)> = reachable
+        .into_iter()
+        .filter(|idx| *idx != start_idx && borders_unrevealed_tile(level, *idx))
+        .map(|idx| {
+            let point = level_utils::idx_point(level.width as u32, idx);
+            (idx, DistanceAlg::Pythagoras.distance2d(start, point))
+        })
+        .collect();
+    frontier.sort_by(|a, b| a.1.partial_cmp(&b.1).unwrap());
+    frontier.into_iter().map(|(idx, _)| idx).collect()
+}
+
+fn get_door_at_idx(world: &World, level: &Level, idx: usize) -> Option<Entity> {
+    let doors = world.read_storage::<Door>();
+    level.tile_content[idx]
+        .iter()
+        .find(|entity| doors.get(**entity).is_some())
+        .copied()
+}
+
+pub fn get_next_step(world: &World) -> AutoExploreStep {
+    let player_ent = world.fetch::<Entity>();
+    let positions = world.read_storage::<Position>();
+    let player_position = positions.get(*player_ent).unwrap();
+    let dungeon = world.fetch::<Dungeon>();
+    let level = dungeon.get_level(player_position.level).unwrap();
+    let next_idx = get_frontier_tiles(level, player_position.idx)
+        .into_iter()
+        .map(|target_idx| a_star_search(player_position.idx, target_idx, level))
+        .find(|path| path.success && path.steps.len() > 1)
+        .map(|path| path.steps[1]);
+    match next_idx {
+        Some(idx) if !level.blocked[idx] => AutoExploreStep::Move(idx),
+        Some(idx) => match get_door_at_idx(world, level, idx) {
+            Some(door) => AutoExploreStep::OpenDoor(door),
+            None => AutoExploreStep::Blocked,
+        },
+        None => AutoExploreStep::Done,
+    }
+}
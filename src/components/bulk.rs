@@ -0,0 +1,13 @@
+use serde::{Deserialize, Serialize};
+use specs::{
+    error::NoError,
+    saveload::{ConvertSaveload, Marker},
+    Component, DenseVecStorage, Entity,
+};
+
+// how heavy an item is in pounds and how much room it takes up
+#[derive(Component, ConvertSaveload, Clone, Debug)]
+pub struct Bulk {
+    pub weight: u32,
+    pub volume: u32,
+}
@@ -0,0 +1,28 @@
+use crate::components::Bulk;
+use serde::{Deserialize, Serialize};
+use specs::{
+    error::NoError,
+    saveload::{ConvertSaveload, Marker},
+    Component, DenseVecStorage, Entity,
+};
+
+// limits what an inventory or container can hold, anything without one is unbounded
+#[derive(Component, ConvertSaveload, Clone, Debug)]
+pub struct Capacity {
+    pub max_weight: u32,
+    pub max_volume: u32,
+}
+
+impl Capacity {
+    pub fn fits_weight(&self, held: &Bulk, item: &Bulk) -> bool {
+        held.weight + item.weight <= self.max_weight
+    }
+
+    pub fn fits_volume(&self, held: &Bulk, item: &Bulk) -> bool {
+        held.volume + item.volume <= self.max_volume
+    }
+
+    pub fn fits(&self, held: &Bulk, item: &Bulk) -> bool {
+        self.fits_weight(held, item) && self.fits_volume(held, item)
+    }
+}
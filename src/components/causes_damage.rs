@@ -10,6 +10,8 @@ pub enum DamageType {
     Burn,
     Crush,
     Pierce,
+    Starve,
+    Poison,
 }
 #[derive(Component, Serialize, Deserialize, Clone, Debug)]
 pub struct CausesDamage {
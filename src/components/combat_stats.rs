@@ -8,3 +8,20 @@ pub struct CombatStats {
   pub defense: i32,
   pub power: i32,
 }
+
+impl CombatStats {
+  pub fn get_health_description(&self) -> &'static str {
+    let ratio = self.hp as f32 / self.max_hp.max(1) as f32;
+    if ratio >= 1.0 {
+      "Unharmed"
+    } else if ratio > 0.75 {
+      "Lightly wounded"
+    } else if ratio > 0.5 {
+      "Wounded"
+    } else if ratio > 0.25 {
+      "Badly wounded"
+    } else {
+      "Near death"
+    }
+  }
+}
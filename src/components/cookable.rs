@@ -0,0 +1,13 @@
+use serde::{Deserialize, Serialize};
+use specs::{
+    error::NoError,
+    saveload::{ConvertSaveload, Marker},
+    Component, DenseVecStorage, Entity,
+};
+
+// raw food that becomes more nourishing once cooked at a lit stove
+#[derive(Component, ConvertSaveload, Clone, Debug)]
+pub struct Cookable {
+    pub cooked_name: String,
+    pub cooked_nutrition: i32,
+}
@@ -27,6 +27,8 @@ impl DamageHistory {
                 DamageType::Stab => "stabbed",
                 DamageType::Pierce => "pierced",
                 DamageType::Crush => "crushed",
+                DamageType::Starve => "starved",
+                DamageType::Poison => "poisoned",
             })
             .collect();
         if terms.len() > 1 {
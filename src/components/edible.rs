@@ -0,0 +1,11 @@
+use serde::{Deserialize, Serialize};
+use specs::{
+    error::NoError,
+    saveload::{ConvertSaveload, Marker},
+    Component, DenseVecStorage, Entity,
+};
+
+#[derive(Component, ConvertSaveload, Clone, Debug)]
+pub struct Edible {
+    pub nutrition: i32,
+}
@@ -0,0 +1,35 @@
+use serde::{Deserialize, Serialize};
+use specs::{
+    error::NoError,
+    saveload::{ConvertSaveload, Marker},
+    Component, DenseVecStorage, Entity,
+};
+
+pub const MAX_NUTRITION: i32 = 1500;
+pub const STARTING_NUTRITION: i32 = 1000;
+pub const HUNGRY_NUTRITION: i32 = 300;
+
+#[derive(PartialEq, Clone, Copy, Debug)]
+pub enum HungerState {
+    Fed,
+    Hungry,
+    Starving,
+}
+
+// nutrition keeps falling below zero while starving
+#[derive(Component, ConvertSaveload, Clone, Debug)]
+pub struct Hunger {
+    pub nutrition: i32,
+}
+
+impl Hunger {
+    pub fn get_state(&self) -> HungerState {
+        if self.nutrition <= 0 {
+            HungerState::Starving
+        } else if self.nutrition <= HUNGRY_NUTRITION {
+            HungerState::Hungry
+        } else {
+            HungerState::Fed
+        }
+    }
+}
@@ -0,0 +1,21 @@
+use serde::{Deserialize, Serialize};
+use specs::{
+    error::NoError,
+    saveload::{ConvertSaveload, Marker},
+    Component, DenseVecStorage, Entity,
+};
+
+#[derive(Serialize, Deserialize, Copy, Clone, Debug, PartialEq)]
+pub enum IdentifiableKind {
+    Potion,
+    Scroll,
+}
+
+// an item that goes by its appearance until its kind is identified, name and description
+// are what the item really is
+#[derive(Component, ConvertSaveload, Clone, Debug)]
+pub struct Identifiable {
+    pub kind: IdentifiableKind,
+    pub name: String,
+    pub description: String,
+}
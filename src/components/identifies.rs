@@ -0,0 +1,6 @@
+use serde::{Deserialize, Serialize};
+use specs::{Component, NullStorage};
+
+#[derive(Component, Serialize, Deserialize, Clone, Debug, Default)]
+#[storage(NullStorage)]
+pub struct Identifies {}
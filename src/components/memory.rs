@@ -1,3 +1,4 @@
+use crate::dungeon::level_id::LevelId;
 use serde::{Deserialize, Serialize};
 use specs::{
     error::NoError,
@@ -8,7 +9,7 @@ use specs::{
 use std::{collections::HashMap, hash::Hash};
 
 #[derive(Serialize, Deserialize, Copy, Clone, Debug)]
-pub struct MemoryLocation(pub i32, pub usize);
+pub struct MemoryLocation(pub LevelId, pub usize);
 
 #[derive(Component, Clone, Debug)]
 pub struct Memory {
@@ -19,7 +20,7 @@ pub struct Memory {
 
 #[derive(Clone, Serialize, Deserialize, Debug)]
 pub struct MemoryData<M: Eq + Copy + Hash> {
-    pub last_known_enemy_positions: Vec<(M, i32, usize)>,
+    pub last_known_enemy_positions: Vec<(M, LevelId, usize)>,
     pub known_enemy_hiding_spots: Vec<(M, M)>,
     pub wander_destination: Option<MemoryLocation>,
 }
@@ -2,16 +2,21 @@ pub mod area_of_effect;
 pub mod armable;
 pub mod blocks_tile;
 pub mod blood;
+pub mod bulk;
+pub mod capacity;
 pub mod causes_damage;
 pub mod causes_fire;
 pub mod causes_light;
 pub mod combat_stats;
 pub mod consumable;
 pub mod container;
+pub mod cookable;
+pub mod cooking_spot;
 pub mod damage_history;
 pub mod disarmable;
 pub mod door;
 pub mod dousable;
+pub mod edible;
 pub mod entity_moved;
 pub mod entry_trigger;
 pub mod equipable;
@@ -23,6 +28,9 @@ pub mod grabbing;
 pub mod hidden;
 pub mod hiding;
 pub mod hiding_spot;
+pub mod hunger;
+pub mod identifiable;
+pub mod identifies;
 pub mod info;
 pub mod inventory;
 pub mod item;
@@ -40,18 +48,23 @@ pub mod position;
 pub mod potion;
 pub mod provides_healing;
 pub mod ranged;
+pub mod regeneration;
 pub mod renderable;
+pub mod rotting;
 pub mod saveable;
 pub mod serialization_helper;
 pub mod single_activation;
+pub mod stackable;
 pub mod suffer_damage;
 pub mod trap;
 pub mod triggered;
 pub mod viewshed;
 pub mod wants_to_close_door;
+pub mod wants_to_cook;
 pub mod wants_to_disarm_trap;
 pub mod wants_to_douse;
 pub mod wants_to_drop_item;
+pub mod wants_to_eat;
 pub mod wants_to_equip;
 pub mod wants_to_exit;
 pub mod wants_to_go_down_stairs;
@@ -71,16 +84,21 @@ pub use area_of_effect::AreaOfEffect;
 pub use armable::Armable;
 pub use blocks_tile::BlocksTile;
 pub use blood::Blood;
+pub use bulk::Bulk;
+pub use capacity::Capacity;
 pub use causes_damage::CausesDamage;
 pub use causes_fire::CausesFire;
 pub use causes_light::CausesLight;
 pub use combat_stats::CombatStats;
 pub use consumable::Consumable;
 pub use container::Container;
+pub use cookable::Cookable;
+pub use cooking_spot::CookingSpot;
 pub use damage_history::DamageHistory;
 pub use disarmable::Disarmable;
 pub use door::Door;
 pub use dousable::Dousable;
+pub use edible::Edible;
 pub use entity_moved::EntityMoved;
 pub use entry_trigger::EntryTrigger;
 pub use equipable::Equipable;
@@ -92,6 +110,9 @@ pub use grabbing::Grabbing;
 pub use hidden::Hidden;
 pub use hiding::Hiding;
 pub use hiding_spot::HidingSpot;
+pub use hunger::Hunger;
+pub use identifiable::Identifiable;
+pub use identifies::Identifies;
 pub use info::Info;
 pub use inventory::Inventory;
 pub use item::Item;
@@ -109,18 +130,23 @@ pub use position::Position;
 pub use potion::Potion;
 pub use provides_healing::ProvidesHealing;
 pub use ranged::Ranged;
+pub use regeneration::Regeneration;
 pub use renderable::Renderable;
+pub use rotting::Rotting;
 pub use saveable::Saveable;
 pub use serialization_helper::SerializationHelper;
 pub use single_activation::SingleActivation;
+pub use stackable::Stackable;
 pub use suffer_damage::SufferDamage;
 pub use trap::Trap;
 pub use triggered::Triggered;
 pub use viewshed::Viewshed;
 pub use wants_to_close_door::WantsToCloseDoor;
+pub use wants_to_cook::WantsToCook;
 pub use wants_to_disarm_trap::WantsToDisarmTrap;
 pub use wants_to_douse::WantsToDouse;
 pub use wants_to_drop_item::WantsToDropItem;
+pub use wants_to_eat::WantsToEat;
 pub use wants_to_equip::WantsToEquip;
 pub use wants_to_exit::WantsToExit;
 pub use wants_to_go_down_stairs::WantsToGoDownStairs;
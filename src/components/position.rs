@@ -1,3 +1,4 @@
+use crate::dungeon::level_id::LevelId;
 use serde::{Deserialize, Serialize};
 use specs::{
   error::NoError,
@@ -8,5 +9,5 @@ use specs::{
 #[derive(Component, ConvertSaveload, Debug, Clone)]
 pub struct Position {
   pub idx: usize,
-  pub level: u8,
+  pub level: LevelId,
 }
@@ -0,0 +1,15 @@
+use serde::{Deserialize, Serialize};
+use specs::{
+  error::NoError,
+  saveload::{ConvertSaveload, Marker},
+  Component, DenseVecStorage, Entity,
+};
+
+// hp comes back one point each time progress builds up to REGENERATION_THRESHOLD
+pub const REGENERATION_THRESHOLD: u32 = 100;
+
+#[derive(Component, ConvertSaveload, Clone, Debug)]
+pub struct Regeneration {
+  pub rate: u32,
+  pub progress: u32,
+}
@@ -0,0 +1,31 @@
+use serde::{Deserialize, Serialize};
+use specs::{
+    error::NoError,
+    saveload::{ConvertSaveload, Marker},
+    Component, DenseVecStorage, Entity,
+};
+
+pub const ROTTEN_AGE: u32 = 300;
+// turns of rot per point of damage from eating it
+const ROT_DAMAGE_TURNS: u32 = 100;
+
+#[derive(Component, ConvertSaveload, Clone, Debug)]
+pub struct Rotting {
+    pub age: u32,
+}
+
+impl Rotting {
+    pub fn is_rotten(&self) -> bool {
+        self.age >= ROTTEN_AGE
+    }
+
+    // rotting food loses half its nutrition by the time it is rotten
+    pub fn get_nutrition(&self, nutrition: i32) -> i32 {
+        let rot = self.age.min(ROTTEN_AGE) as f32 / ROTTEN_AGE as f32;
+        (nutrition as f32 * (1.0 - rot / 2.0)) as i32
+    }
+
+    pub fn get_damage(&self) -> i32 {
+        1 + (self.age / ROT_DAMAGE_TURNS) as i32
+    }
+}
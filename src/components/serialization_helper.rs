@@ -1,4 +1,5 @@
 use crate::dungeon::dungeon::Dungeon;
+use crate::services::Identification;
 use serde::{Deserialize, Serialize};
 use specs::{
   error::NoError,
@@ -6,7 +7,44 @@ use specs::{
   Component, DenseVecStorage, Entity,
 };
 
-#[derive(Component, ConvertSaveload, Debug, Clone)]
+#[derive(Component, Debug, Clone)]
 pub struct SerializationHelper {
   pub dungeon: Dungeon,
+  pub identification: Identification,
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct SerializationHelperData {
+  pub dungeon: Dungeon,
+  // saves from before identification existed start with nothing identified
+  #[serde(default = "Identification::new")]
+  pub identification: Identification,
+}
+
+impl<M: Marker + Serialize> ConvertSaveload<M> for SerializationHelper
+where
+  for<'de> M: Deserialize<'de>,
+{
+  type Data = SerializationHelperData;
+  type Error = NoError;
+
+  fn convert_into<F>(&self, _ids: F) -> Result<Self::Data, Self::Error>
+  where
+    F: FnMut(Entity) -> Option<M>,
+  {
+    Ok(SerializationHelperData {
+      dungeon: self.dungeon.clone(),
+      identification: self.identification.clone(),
+    })
+  }
+
+  fn convert_from<F>(data: Self::Data, _ids: F) -> Result<Self, Self::Error>
+  where
+    F: FnMut(M) -> Option<Entity>,
+  {
+    Ok(SerializationHelper {
+      dungeon: data.dungeon,
+      identification: data.identification,
+    })
+  }
 }
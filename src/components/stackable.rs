@@ -0,0 +1,12 @@
+use serde::{Deserialize, Serialize};
+use specs::{
+    error::NoError,
+    saveload::{ConvertSaveload, Marker},
+    Component, DenseVecStorage, Entity,
+};
+
+// items of the same name that can be held as one entity
+#[derive(Component, ConvertSaveload, Clone, Debug)]
+pub struct Stackable {
+    pub quantity: u32,
+}
@@ -0,0 +1,6 @@
+use specs::{Component, DenseVecStorage, Entity};
+
+#[derive(Component, Debug, Clone)]
+pub struct WantsToCook {
+    pub stove: Entity,
+}
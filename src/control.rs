@@ -1,25 +1,43 @@
+use crate::gamepad::{self, GamepadButton, StickDirection};
+use crate::patches;
 use core::fmt;
 use rltk::{Rltk, VirtualKeyCode};
 use serde::{Deserialize, Serialize};
 use std::hash::Hash;
 use std::{collections::HashMap, fmt::Display};
 
-#[derive(Hash, Eq, PartialEq, Serialize, Deserialize, Copy, Clone, Debug)]
-pub enum InputModifier {
-    Shift,
-    Control,
+// any combination of modifiers can be held for a control, the ones left out of a
+// control's definition must not be held
+#[derive(Hash, Eq, PartialEq, Serialize, Deserialize, Copy, Clone, Debug, Default)]
+#[serde(default)]
+pub struct InputModifiers {
+    pub shift: bool,
+    pub control: bool,
+    pub alt: bool,
+}
+
+impl InputModifiers {
+    fn from_context(context: &Rltk) -> Self {
+        Self {
+            shift: context.shift,
+            control: context.control,
+            alt: context.alt,
+        }
+    }
 }
 
-impl Display for InputModifier {
+impl Display for InputModifiers {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(
-            f,
-            "{}",
-            match self {
-                Self::Shift => "Shift",
-                Self::Control => "Control",
-            }
-        )
+        let names: Vec<&str> = [
+            (self.shift, "Shift"),
+            (self.control, "Control"),
+            (self.alt, "Alt"),
+        ]
+        .iter()
+        .filter(|(held, _)| *held)
+        .map(|(_, name)| *name)
+        .collect();
+        write!(f, "{}", names.join(" + "))
     }
 }
 
@@ -28,6 +46,8 @@ pub enum Input {
     Key(VirtualKeyCode),
     LeftClick,
     RightClick,
+    GamepadButton(GamepadButton),
+    LeftStick(StickDirection),
 }
 
 impl Display for Input {
@@ -199,61 +219,97 @@ impl Display for Input {
                 Self::Key(VirtualKeyCode::Cut) => "Cut",
                 Self::LeftClick => "Mouse Left",
                 Self::RightClick => "Mouse Right",
+                Self::GamepadButton(button) => button.get_name(),
+                Self::LeftStick(direction) => direction.get_name(),
             }
         )
     }
 }
 
+impl Input {
+    // modifier keys are only ever held alongside another input
+    fn is_modifier_key(&self) -> bool {
+        match self {
+            Self::Key(VirtualKeyCode::LShift)
+            | Self::Key(VirtualKeyCode::RShift)
+            | Self::Key(VirtualKeyCode::LControl)
+            | Self::Key(VirtualKeyCode::RControl)
+            | Self::Key(VirtualKeyCode::LAlt)
+            | Self::Key(VirtualKeyCode::RAlt) => true,
+            _ => false,
+        }
+    }
+}
+
+// bindings saved before modifiers could be combined hold a single optional modifier
+#[derive(Deserialize, Copy, Clone, Debug)]
+enum LegacyInputModifier {
+    Shift,
+    Control,
+}
+
+#[derive(Deserialize)]
+struct SavedControl {
+    #[serde(default)]
+    modifiers: InputModifiers,
+    #[serde(default)]
+    modifier: Option<LegacyInputModifier>,
+    input: Input,
+}
+
+impl From<SavedControl> for Control {
+    fn from(saved: SavedControl) -> Self {
+        let mut modifiers = saved.modifiers;
+        match saved.modifier {
+            Some(LegacyInputModifier::Shift) => modifiers.shift = true,
+            Some(LegacyInputModifier::Control) => modifiers.control = true,
+            None => {}
+        }
+        Self {
+            modifiers,
+            input: saved.input,
+        }
+    }
+}
+
 #[derive(Copy, Clone, Hash, Eq, PartialEq, Serialize, Deserialize, Debug)]
+#[serde(from = "SavedControl")]
 pub struct Control {
-    modifier: Option<InputModifier>,
+    modifiers: InputModifiers,
     input: Input,
 }
 
 impl Control {
-    fn modifier_from_context(context: &Rltk) -> Option<InputModifier> {
-        if context.shift {
-            Some(InputModifier::Shift)
-        } else if context.control {
-            Some(InputModifier::Control)
-        } else {
-            None
-        }
-    }
     fn input_from_context(context: &Rltk) -> Option<Input> {
         match context.key {
             Some(k) => Some(Input::Key(k)),
             None => {
                 if context.left_click {
                     Some(Input::LeftClick)
+                } else if patches::right_clicked() {
+                    Some(Input::RightClick)
                 } else {
-                    None
+                    gamepad::get_pressed()
                 }
             }
         }
     }
     pub fn from_context(context: &Rltk) -> Option<Self> {
         match Self::input_from_context(context) {
-            Some(input) => Some(Self {
+            Some(input) if !input.is_modifier_key() => Some(Self {
                 input,
-                modifier: Self::modifier_from_context(context),
+                modifiers: InputModifiers::from_context(context),
             }),
-            None => None,
+            _ => None,
         }
     }
 }
 impl Display for Control {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(
-            f,
-            "{}",
-            match self.modifier {
-                Some(modifier) => {
-                    format!("{} + {}", &modifier, self.input)
-                }
-                None => self.input.to_string(),
-            }
-        )
+        match self.modifiers == InputModifiers::default() {
+            true => write!(f, "{}", self.input),
+            false => write!(f, "{} + {}", self.modifiers, self.input),
+        }
     }
 }
 
@@ -263,40 +319,34 @@ pub struct ControlMap<T: Eq> {
 }
 
 impl<T: Eq> ControlMap<T> {
+    pub fn get_value(&self, control: &Control) -> Option<&T> {
+        self.map.get(control)
+    }
+
     pub fn get_value_with_context(&self, context: &Rltk) -> Option<&T> {
         match Control::from_context(context) {
-            Some(c) => self.map.get(&c),
+            Some(c) => self.get_value(&c),
             None => None,
         }
     }
 
-    pub fn get_control_for_value(&self, value: &T) -> Option<&Control> {
-        self.map.iter().find_map(|(k, v)| match v == value {
-            true => Some(k),
-            false => None,
-        })
+    // sorted so that bindings are always listed in the same order
+    pub fn get_controls_for_value(&self, value: &T) -> Vec<&Control> {
+        let mut controls: Vec<&Control> = self
+            .map
+            .iter()
+            .filter(|(_, v)| *v == value)
+            .map(|(k, _)| k)
+            .collect();
+        controls.sort_by_key(|c| c.to_string());
+        controls
     }
 
-    pub fn insert_with_context(&mut self, context: &Rltk, value: T) {
-        match Control::from_context(context) {
-            Some(c) => self.map.insert(c, value),
-            None => None,
-        };
-    }
-
-    pub fn remove(&mut self, control: &Control) {
-        self.map.remove(control);
+    pub fn insert(&mut self, control: Control, value: T) {
+        self.map.insert(control, value);
     }
 
     pub fn remove_by_value(&mut self, value: &T) {
-        let control = {
-            self.map.iter().find_map(|(k, v)| match v == value {
-                true => Some(k.clone()),
-                false => None,
-            })
-        };
-        if let Some(control) = control {
-            self.remove(&control);
-        }
+        self.map.retain(|_, v| v != value);
     }
 }
@@ -19,3 +19,31 @@ pub const CTA_INTERACT_OPEN_CONTAINER: &str =
     "Press LEFT and RIGHT to select a container to open, ESC to cancel";
 pub const CTA_INTERACT_OPEN_DOOR: &str =
     "Press LEFT and RIGHT to select a door to open, ESC to cancel";
+pub const LOG_INTRO: &str = "Enter the dungeon apprentice! Bring back the Talisman!";
+pub const CTA_MESSAGE_LOG: &str =
+    "Press UP and DOWN to scroll, < and > to page, LEFT and RIGHT to filter, ESC to close";
+pub const CTA_EXAMINE: &str = "Use the movement keys to move the cursor, ESC to stop examining";
+pub const EXAMINE_UNEXPLORED: &str = "You have not explored this area";
+pub const EXAMINE_NOT_VISIBLE: &str = "You cannot currently see this area";
+pub const EXAMINE_REMEMBERED: &str = "You remember seeing:";
+pub const AUTO_EXPLORE_DONE: &str = "There is nothing left to explore here";
+pub const AUTO_EXPLORE_BLOCKED: &str = "Something blocks your way";
+pub const TRAVEL_UNKNOWN_DOWN_STAIRS: &str = "You have not found the stairs down";
+pub const TRAVEL_UNKNOWN_UP_STAIRS: &str = "You have not found the stairs up";
+pub const TRAVEL_UNKNOWN_EXIT: &str = "You have not found the exit";
+pub const TRAVEL_NO_LANDMARKS: &str = "You do not know of any landmarks on this level";
+pub const REST_MONSTERS_IN_VIEW: &str = "You cannot rest with enemies in view";
+pub const REST_BURNING: &str = "You cannot rest while you are burning";
+pub const REST_HUNGRY: &str = "You are too hungry to rest";
+pub const REST_HEALED: &str = "You feel fully rested";
+pub const REPEAT_NOTHING: &str = "You have done nothing that can be repeated";
+pub const CTA_REPEAT: &str = "Escape to Cancel";
+pub const MACRO_RECORDING: &str = "Recording macro, press record again to stop";
+pub const MACRO_SAVED: &str = "Macro saved";
+pub const MACRO_EMPTY: &str = "Nothing has been recorded for that macro";
+pub const CTA_OVERVIEW: &str = "Press ESC to return to the map";
+pub const CTA_OPTIONS: &str =
+    "Press LEFT and RIGHT to change tabs, Enter to change an option, R to reset to defaults, ESC to save and exit";
+pub const CTA_RESET_OPTIONS: &str = "Enter to select, ESC to cancel";
+pub const CTA_OPTIONS_CONTROLS: &str =
+    "Press LEFT and RIGHT to change tabs, Enter to add a binding, Delete to clear, R to reset to defaults, ESC to save and exit";
@@ -0,0 +1,63 @@
+use super::level_id::LevelId;
+use serde::{Deserialize, Serialize};
+
+#[derive(PartialEq, Eq, Hash, Serialize, Deserialize, Clone, Copy, Debug)]
+pub enum Branch {
+    Main,
+    FloodedCistern,
+    GoblinWarren,
+}
+
+impl Default for Branch {
+    fn default() -> Self {
+        Self::Main
+    }
+}
+
+pub const SIDE_BRANCHES: [Branch; 2] = [Branch::FloodedCistern, Branch::GoblinWarren];
+
+impl Branch {
+    pub fn get_name(&self) -> &str {
+        match self {
+            Self::Main => "Depth",
+            Self::FloodedCistern => "Flooded Cistern",
+            Self::GoblinWarren => "Goblin Warren",
+        }
+    }
+
+    pub fn get_floor_count(&self) -> u8 {
+        match self {
+            Self::Main => 10,
+            Self::FloodedCistern => 2,
+            Self::GoblinWarren => 3,
+        }
+    }
+
+    // The depth of the main level that holds the stairs down into this branch.
+    pub fn get_entrance_depth(&self) -> Option<u8> {
+        match self {
+            Self::Main => None,
+            Self::FloodedCistern => Some(7),
+            Self::GoblinWarren => Some(4),
+        }
+    }
+
+    // Depths count down as the player descends, side branches carry on from the depth below their
+    // entrance.
+    pub fn get_top_depth(&self) -> u8 {
+        match self.get_entrance_depth() {
+            Some(depth) => depth - 1,
+            None => self.get_floor_count() - 1,
+        }
+    }
+
+    pub fn get_bottom_depth(&self) -> u8 {
+        self.get_top_depth() + 1 - self.get_floor_count()
+    }
+
+    pub fn get_level_ids(&self) -> Vec<LevelId> {
+        (self.get_bottom_depth()..=self.get_top_depth())
+            .map(|depth| LevelId::new(*self, depth))
+            .collect()
+    }
+}
@@ -1,18 +1,54 @@
-use super::level::Level;
+use super::{level::Level, level_id::LevelId};
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 
+// Level ids aren't strings so the levels are saved as a list of pairs rather than a map. Saves
+// from before there were branches keyed the levels by depth, the level knows its own id so the
+// key can be ignored.
+mod levels_as_pairs {
+  use super::{Level, LevelId};
+  use serde::{Deserialize, Deserializer, Serializer};
+  use std::collections::HashMap;
+
+  #[derive(Deserialize)]
+  #[serde(untagged)]
+  enum SavedLevels {
+    Pairs(Vec<(LevelId, Level)>),
+    ByDepth(HashMap<String, Level>),
+  }
+
+  pub fn serialize<S: Serializer>(
+    levels: &HashMap<LevelId, Level>,
+    serializer: S,
+  ) -> Result<S::Ok, S::Error> {
+    serializer.collect_seq(levels.iter())
+  }
+
+  pub fn deserialize<'de, D: Deserializer<'de>>(
+    deserializer: D,
+  ) -> Result<HashMap<LevelId, Level>, D::Error> {
+    Ok(match SavedLevels::deserialize(deserializer)? {
+      SavedLevels::Pairs(pairs) => pairs.into_iter().collect(),
+      SavedLevels::ByDepth(levels) => levels
+        .into_iter()
+        .map(|(_depth, level)| (level.id, level))
+        .collect(),
+    })
+  }
+}
+
 #[derive(Default, Serialize, Deserialize, Clone, Debug)]
 pub struct Dungeon {
-  pub levels: HashMap<u8, Level>,
+  #[serde(with = "levels_as_pairs")]
+  pub levels: HashMap<LevelId, Level>,
 }
 
 impl Dungeon {
-  pub fn get_level(&self, floor: u8) -> Option<&Level> {
-    self.levels.get(&floor)
+  pub fn get_level(&self, id: LevelId) -> Option<&Level> {
+    self.levels.get(&id)
   }
 
-  pub fn get_level_mut(&mut self, floor: u8) -> Option<&mut Level> {
-    self.levels.get_mut(&floor)
+  pub fn get_level_mut(&mut self, id: LevelId) -> Option<&mut Level> {
+    self.levels.get_mut(&id)
   }
 }
@@ -1,5 +1,8 @@
+use super::branch::Branch;
 use super::constants::{MAP_COUNT, MAP_HEIGHT, MAP_WIDTH};
+use super::level_id::LevelId;
 use super::level_utils;
+use super::remembered_entity::RememberedEntity;
 use super::room::Room;
 use super::tile_type::TileType;
 use rltk::{Algorithm2D, BaseMap, DistanceAlg::Pythagoras, Point, SmallVec};
@@ -16,16 +19,26 @@ pub struct Level {
     pub lit_tiles: Box<[bool]>, // can we skip serializing this?d
     pub blocked: Box<[bool]>,
     pub opaque: Box<[bool]>,
-    pub depth: u8,
+    // older saves only have the depth
+    #[serde(default, alias = "depth")]
+    pub id: LevelId,
     pub stairs_down: Option<usize>,
     pub stairs_up: Option<usize>,
     pub exit: Option<usize>,
+    #[serde(default)]
+    pub secret_doors: Vec<usize>,
+    // extra down stairs leading into side branches
+    #[serde(default)]
+    pub branch_stairs: Vec<(usize, Branch)>,
+    // what the player last saw on tiles that are no longer in view
+    #[serde(default)]
+    pub remembered_entities: Vec<RememberedEntity>,
     #[serde(skip_serializing, skip_deserializing)]
     pub tile_content: Vec<Vec<Entity>>,
 }
 
 impl Level {
-    pub fn new(depth: u8) -> Self {
+    pub fn new(id: LevelId) -> Self {
         Self {
             tiles: Box::new([TileType::Wall; MAP_COUNT]),
             rooms: vec![], // TODO: determine if this is useful beyond the level building phase
@@ -39,9 +52,26 @@ impl Level {
             stairs_down: None,
             stairs_up: None,
             exit: None,
-            depth,
+            secret_doors: vec![],
+            branch_stairs: vec![],
+            remembered_entities: vec![],
+            id,
         }
     }
+    pub fn get_branch_stairs(&self, branch: Branch) -> Option<usize> {
+        self.branch_stairs
+            .iter()
+            .find(|(_, b)| *b == branch)
+            .map(|(idx, _)| *idx)
+    }
+
+    pub fn get_branch_at_idx(&self, idx: usize) -> Option<Branch> {
+        self.branch_stairs
+            .iter()
+            .find(|(i, _)| *i == idx)
+            .map(|(_, branch)| *branch)
+    }
+
     pub fn get_costs_for_tile(&self, idx: usize, diagonal: bool) -> f32 {
         let cost = match diagonal {
             true => 1.45,
@@ -0,0 +1,77 @@
+use super::{add_corridor, add_rectangular_room, add_room_details, LevelBuilder};
+use crate::dungeon::{level::Level, rect::Rect, room::Room, vaults};
+use rltk::{Point, RandomNumberGenerator};
+
+const MIN_LEAF_SIZE: i32 = 8;
+const MAX_LEAF_SIZE: i32 = 20;
+
+// A tightly packed fortress, the level is split in two over and over and every leaf gets a room.
+// Sibling leaves are joined by a corridor so every room is reachable.
+pub struct BspFortressBuilder {}
+
+fn get_room_for_leaf(leaf: &Rect, rng: &mut RandomNumberGenerator) -> Rect {
+    let x = leaf.x1 + rng.range(0, 2);
+    let y = leaf.y1 + rng.range(0, 2);
+    let width = leaf.x2 - x - rng.range(0, 2);
+    let height = leaf.y2 - y - rng.range(0, 2);
+    Rect::new(x, y, width, height)
+}
+
+// Returns the center of one of the rooms in the leaf so that it can be joined to its sibling.
+fn split_leaf(
+    level: &mut Level,
+    rng: &mut RandomNumberGenerator,
+    leaf: Rect,
+    rooms: &mut Vec<Rect>,
+) -> Point {
+    let can_split_x = leaf.width() >= MIN_LEAF_SIZE * 2;
+    let can_split_y = leaf.height() >= MIN_LEAF_SIZE * 2;
+    let is_small_enough = leaf.width() <= MAX_LEAF_SIZE && leaf.height() <= MAX_LEAF_SIZE;
+    let split_x = match (can_split_x, can_split_y) {
+        (false, false) => None,
+        _ if is_small_enough && rng.range(0, 3) == 0 => None,
+        (true, false) => Some(true),
+        (false, true) => Some(false),
+        (true, true) => Some(leaf.width() > leaf.height()),
+    };
+    let (first, second) = match split_x {
+        None => {
+            let room = get_room_for_leaf(&leaf, rng);
+            add_rectangular_room(level, &room);
+            rooms.push(room);
+            return Point::from(room.center());
+        }
+        Some(true) => {
+            let x = rng.range(leaf.x1 + MIN_LEAF_SIZE, leaf.x2 - MIN_LEAF_SIZE + 1);
+            (
+                Rect::new(leaf.x1, leaf.y1, x - leaf.x1, leaf.height()),
+                Rect::new(x, leaf.y1, leaf.x2 - x, leaf.height()),
+            )
+        }
+        Some(false) => {
+            let y = rng.range(leaf.y1 + MIN_LEAF_SIZE, leaf.y2 - MIN_LEAF_SIZE + 1);
+            (
+                Rect::new(leaf.x1, leaf.y1, leaf.width(), y - leaf.y1),
+                Rect::new(leaf.x1, y, leaf.width(), leaf.y2 - y),
+            )
+        }
+    };
+    let first_center = split_leaf(level, rng, first, rooms);
+    let second_center = split_leaf(level, rng, second, rooms);
+    add_corridor(level, rng, first_center, second_center);
+    match rng.range(0, 2) {
+        0 => first_center,
+        _ => second_center,
+    }
+}
+
+impl LevelBuilder for BspFortressBuilder {
+    fn build_layout(&mut self, level: &mut Level, rng: &mut RandomNumberGenerator) {
+        let mut room_rects = vec![];
+        let bounds = Rect::new(1, 1, level.width as i32 - 3, level.height as i32 - 3);
+        split_leaf(level, rng, bounds, &mut room_rects);
+        level.rooms = room_rects.iter().map(|r| Room::new(*r, rng)).collect();
+        vaults::assign_vaults_to_rooms(level, rng);
+        add_room_details(level, rng);
+    }
+}
@@ -0,0 +1,42 @@
+use super::{remove_unreachable_areas, LevelBuilder};
+use crate::dungeon::{level::Level, level_utils, tile_type::TileType};
+use rltk::RandomNumberGenerator;
+
+const FLOOR_CHANCE: i32 = 55;
+const SMOOTHING_PASSES: i32 = 12;
+
+// Natural caves, the level starts as noise and is smoothed out until only open caverns remain.
+pub struct CellularAutomataBuilder {}
+
+impl LevelBuilder for CellularAutomataBuilder {
+    fn build_layout(&mut self, level: &mut Level, rng: &mut RandomNumberGenerator) {
+        let width = level.width as i32;
+        let height = level.height as i32;
+        for y in 1..height - 1 {
+            for x in 1..width - 1 {
+                let idx = level_utils::xy_idx(width as u32, x, y);
+                level.tiles[idx] = match rng.range(0, 100) < FLOOR_CHANCE {
+                    true => TileType::Floor,
+                    false => TileType::Wall,
+                };
+            }
+        }
+        for _ in 0..SMOOTHING_PASSES {
+            let previous_tiles = level.tiles.clone();
+            for y in 1..height - 1 {
+                for x in 1..width - 1 {
+                    let idx = level_utils::xy_idx(width as u32, x, y);
+                    let wall_count = level_utils::get_neighbors_for_idx(width, idx as i32)
+                        .iter()
+                        .filter(|neighbor| previous_tiles[**neighbor as usize] == TileType::Wall)
+                        .count();
+                    level.tiles[idx] = match wall_count {
+                        0 | 5..=8 => TileType::Wall,
+                        _ => TileType::Floor,
+                    };
+                }
+            }
+        }
+        remove_unreachable_areas(level);
+    }
+}
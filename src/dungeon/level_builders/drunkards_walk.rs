@@ -0,0 +1,39 @@
+use super::LevelBuilder;
+use crate::dungeon::{level::Level, level_utils, tile_type::TileType};
+use rltk::RandomNumberGenerator;
+
+const FLOOR_PERCENT: usize = 40;
+const STEPS_PER_DIGGER: i32 = 400;
+
+// Winding mine tunnels, diggers wander at random until enough of the level has been dug out. Each
+// digger starts from a tile that has already been dug so the mine is always connected.
+pub struct DrunkardsWalkBuilder {}
+
+impl LevelBuilder for DrunkardsWalkBuilder {
+    fn build_layout(&mut self, level: &mut Level, rng: &mut RandomNumberGenerator) {
+        let width = level.width as i32;
+        let height = level.height as i32;
+        let desired_floor_count = level.tiles.len() * FLOOR_PERCENT / 100;
+        let mut floor_count = 0;
+        while floor_count < desired_floor_count {
+            let (mut x, mut y) = match level_utils::get_random_unblocked_floor_point(level, rng) {
+                Some(idx) => level_utils::idx_xy(width as u32, idx),
+                None => (width / 2, height / 2),
+            };
+            for _ in 0..STEPS_PER_DIGGER {
+                let idx = level_utils::xy_idx(width as u32, x, y);
+                if level.tiles[idx] == TileType::Wall {
+                    level_utils::set_tile_to_floor(level, idx);
+                    floor_count += 1;
+                }
+                match rng.range(0, 4) {
+                    0 if x > 2 => x -= 1,
+                    1 if x < width - 3 => x += 1,
+                    2 if y > 2 => y -= 1,
+                    3 if y < height - 3 => y += 1,
+                    _ => {}
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,27 @@
+use super::{CellularAutomataBuilder, LevelBuilder};
+use crate::dungeon::{level::Level, level_utils, tile_type::TileType};
+use rltk::RandomNumberGenerator;
+
+const MIN_POOLS: i32 = 4;
+const MAX_POOLS: i32 = 8;
+const MAX_POOL_RADIUS: i32 = 6;
+
+// Caves that have been partly swallowed by deep pools of water.
+pub struct FloodedCisternBuilder {}
+
+impl LevelBuilder for FloodedCisternBuilder {
+    fn build_layout(&mut self, level: &mut Level, rng: &mut RandomNumberGenerator) {
+        CellularAutomataBuilder {}.build_layout(level, rng);
+        for _ in 0..rng.range(MIN_POOLS, MAX_POOLS + 1) {
+            if let Some(center_idx) = level_utils::get_random_unblocked_floor_point(level, rng) {
+                let radius = rng.range(2, MAX_POOL_RADIUS + 1) as u32;
+                for idx in level_utils::get_all_unblocked_tiles_in_radius(level, center_idx, radius)
+                {
+                    if level.tiles[idx] == TileType::Floor {
+                        level.tiles[idx] = TileType::WaterDeep;
+                    }
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,599 @@
+mod bsp_fortress;
+mod cellular_automata;
+mod drunkards_walk;
+mod flooded_cistern;
+mod rooms_and_corridors;
+mod validation;
+
+pub use bsp_fortress::BspFortressBuilder;
+pub use cellular_automata::CellularAutomataBuilder;
+pub use drunkards_walk::DrunkardsWalkBuilder;
+pub use flooded_cistern::FloodedCisternBuilder;
+pub use rooms_and_corridors::RoomsAndCorridorsBuilder;
+pub use validation::{get_reachable_tiles, repair_level, validate_level, UnreachableTarget};
+
+use super::room_feature::RoomFeature::{
+    ColumnsDoubleAll, ColumnsDoubleBottom, ColumnsDoubleHorizontal, ColumnsDoubleLeft,
+    ColumnsDoubleMiddle, ColumnsDoubleRight, ColumnsDoubleTop, ColumnsDoubleVertical,
+    ColumnsSingleAll, ColumnsSingleBottom, ColumnsSingleHorizontal, ColumnsSingleLeft,
+    ColumnsSingleMiddle, ColumnsSingleRight, ColumnsSingleTop, ColumnsSingleVertical,
+    ColumnsTripleAll, ColumnsTripleBottom, ColumnsTripleHorizontal, ColumnsTripleLeft,
+    ColumnsTripleRight, ColumnsTripleTop, ColumnsTripleVertical,
+};
+use super::{
+    branch::Branch,
+    column_placers,
+    level::Level,
+    level_id::LevelId,
+    level_utils,
+    rect::Rect,
+    room_decorators,
+    room_decorators::{
+        RoomPart,
+        RoomPart::{
+            Column, Door, DownStairs, Exit, Floor, ItemSpawn, Ledge, MonsterSpawn, UpStairs, Wall,
+            WaterDeep,
+        },
+    },
+    tile_type::TileType,
+    vaults,
+};
+use rltk::{DistanceAlg::Pythagoras, Point, RandomNumberGenerator};
+use stamp_rs::{
+    StampPart,
+    StampPart::{Transparent, Use},
+};
+use std::cmp;
+use std::collections::HashSet;
+
+const MAX_BUILD_ATTEMPTS: i32 = 5;
+
+pub trait LevelBuilder {
+    // Carves out the layout of the level, builders that lay out rooms should fill in `level.rooms`
+    // so that the rooms are stamped and decorated afterwards.
+    fn build_layout(&mut self, level: &mut Level, rng: &mut RandomNumberGenerator);
+}
+
+fn update_level_from_room_features(level: &mut Level, rng: &mut RandomNumberGenerator) {
+    let mut updates: Vec<((i32, i32), TileType)> = vec![];
+    for room in &level.rooms {
+        let rect = &room.rect;
+        for feature in &room.features {
+            let mut additional_updates: Vec<((i32, i32), TileType)> = match feature {
+                Some(ColumnsSingleLeft) => column_placers::get_columns_left(rect, rng, 1),
+                Some(ColumnsDoubleLeft) => column_placers::get_columns_left(rect, rng, 2),
+                Some(ColumnsTripleLeft) => column_placers::get_columns_left(rect, rng, 3),
+                Some(ColumnsSingleRight) => column_placers::get_columns_right(rect, rng, 1),
+                Some(ColumnsDoubleRight) => column_placers::get_columns_right(rect, rng, 2),
+                Some(ColumnsTripleRight) => column_placers::get_columns_right(rect, rng, 3),
+                Some(ColumnsSingleBottom) => column_placers::get_columns_bottom(rect, rng, 1),
+                Some(ColumnsDoubleBottom) => column_placers::get_columns_bottom(rect, rng, 2),
+                Some(ColumnsTripleBottom) => column_placers::get_columns_bottom(rect, rng, 3),
+                Some(ColumnsSingleTop) => column_placers::get_columns_top(rect, rng, 1),
+                Some(ColumnsDoubleTop) => column_placers::get_columns_top(rect, rng, 2),
+                Some(ColumnsTripleTop) => column_placers::get_columns_top(rect, rng, 3),
+                Some(ColumnsSingleVertical) => column_placers::get_columns_vertical(rect, rng, 1),
+                Some(ColumnsDoubleVertical) => column_placers::get_columns_vertical(rect, rng, 2),
+                Some(ColumnsTripleVertical) => column_placers::get_columns_vertical(rect, rng, 3),
+                Some(ColumnsSingleHorizontal) => {
+                    column_placers::get_columns_horizontal(rect, rng, 1)
+                }
+                Some(ColumnsDoubleHorizontal) => {
+                    column_placers::get_columns_horizontal(rect, rng, 2)
+                }
+                Some(ColumnsTripleHorizontal) => {
+                    column_placers::get_columns_horizontal(rect, rng, 3)
+                }
+                Some(ColumnsSingleAll) => column_placers::get_columns_all(rect, rng, 1),
+                Some(ColumnsDoubleAll) => column_placers::get_columns_all(rect, rng, 2),
+                Some(ColumnsTripleAll) => column_placers::get_columns_all(rect, rng, 3),
+                Some(ColumnsSingleMiddle) => column_placers::get_columns_single_middle(rect),
+                Some(ColumnsDoubleMiddle) => column_placers::get_columns_double_middle(rect, rng),
+                _ => vec![],
+            };
+            &mut updates.append(&mut additional_updates);
+        }
+    }
+    let level_width = level.width as u32;
+    for (xy, tile_type) in &updates {
+        if !level_utils::tile_at_xy_is_wall(level, xy.0, xy.1)
+            && !level_utils::tile_is_between_walls(level, xy.0, xy.1)
+            && !level_utils::tile_is_door_adjacent(level, xy.0, xy.1)
+        {
+            let idx = level_utils::xy_idx(level_width, xy.0, xy.1);
+            level.tiles[idx as usize] = *tile_type;
+        }
+    }
+}
+
+fn update_level_from_room_stamps(level: &mut Level) {
+    let mut updates: Vec<(usize, usize, Option<TileType>)> = vec![];
+    for room in level.rooms.iter() {
+        // only vaults are allowed to reshape the walls and floor of their room
+        let is_vault = room.vault.is_some();
+        for x in room.rect.x1..room.rect.x2 {
+            for y in room.rect.y1..room.rect.y2 {
+                let room_x = x - room.rect.x1;
+                let room_y = y - room.rect.y1;
+                let tile_type = match room.stamp.get_at((room_x as usize, room_y as usize)) {
+                    Some(Use(Ledge)) => Some(TileType::Ledge),
+                    Some(Use(WaterDeep)) => Some(TileType::WaterDeep),
+                    Some(Use(Wall)) if is_vault => Some(TileType::Wall),
+                    Some(Use(Column)) if is_vault => Some(TileType::Column),
+                    Some(Use(Door)) if is_vault => Some(TileType::Door),
+                    Some(Use(Floor)) | Some(Use(MonsterSpawn)) | Some(Use(ItemSpawn))
+                        if is_vault =>
+                    {
+                        Some(TileType::Floor)
+                    }
+                    _ => None,
+                };
+                updates.push((x as usize, y as usize, tile_type));
+            }
+        }
+    }
+    let width = level.width as u32;
+    updates.iter().for_each(|(x, y, tile_type)| {
+        let idx = level_utils::xy_idx(width, *x as i32, *y as i32);
+        if let Some(t) = tile_type {
+            level.tiles[idx as usize] = *t;
+        }
+    });
+}
+
+fn decorate_level(level: &mut Level, rng: &mut RandomNumberGenerator) {
+    level
+        .rooms
+        .iter_mut()
+        .filter(|room| room.vault.is_none())
+        .for_each(|room| {
+            room_decorators::decorate_room(&mut room.stamp, &room.room_type, rng);
+        });
+}
+
+fn update_room_stamps_from_level(level: &mut Level) {
+    let mut updates: Vec<(usize, usize, usize, StampPart<RoomPart>)> = vec![];
+    level.rooms.iter_mut().for_each(|room| {
+        // the rect needs to be expanded at this point so that the stamp can have all 4 walls
+        // TODO: This feels messey and should be part of a level generation refactor.
+        room.rect.x2 = room.rect.x2 + 1;
+        room.rect.y2 = room.rect.y2 + 1;
+    });
+    for (room_index, room) in level.rooms.iter().enumerate() {
+        for x in room.rect.x1..room.rect.x2 {
+            for y in room.rect.y1..room.rect.y2 {
+                let room_x = x - room.rect.x1;
+                let room_y = y - room.rect.y1;
+                let room_stamp_part = match level_utils::get_tile_at_xy(level, x, y) {
+                    Some(&TileType::Floor) => Use(Floor),
+                    Some(&TileType::Wall) => Use(Wall),
+                    Some(&TileType::Door) => Use(Door),
+                    Some(&TileType::DownStairs) => Use(DownStairs),
+                    Some(&TileType::UpStairs) => Use(UpStairs),
+                    Some(&TileType::Exit) => Use(Exit),
+                    Some(&TileType::Column) => Use(Column),
+                    Some(&TileType::Ledge) => Use(Ledge),
+                    Some(&TileType::WaterDeep) => Use(WaterDeep),
+                    None => Transparent,
+                };
+                updates.push((
+                    room_index,
+                    room_x as usize,
+                    room_y as usize,
+                    room_stamp_part,
+                ));
+            }
+        }
+    }
+    updates
+        .iter()
+        .for_each(|(room_index, room_x, room_y, stamp_part)| {
+            level.rooms[*room_index]
+                .stamp
+                .set_at((*room_x, *room_y), stamp_part.clone());
+        });
+}
+
+fn add_rectangular_room(level: &mut Level, room: &Rect) {
+    let width = level.width as u32;
+    for y in room.y1 + 1..room.y2 {
+        for x in room.x1 + 1..room.x2 {
+            let idx = level_utils::xy_idx(width, x, y) as usize;
+            level_utils::set_tile_to_floor(level, idx);
+        }
+    }
+}
+
+fn add_circular_room(level: &mut Level, room: &Rect) {
+    let radius = i32::min(room.x2 - room.x1, room.y2 - room.y1) as f32 / 2.0;
+    let center_point = Point::from(room.center());
+    let width = level.width as u32;
+    for y in room.y1 + 1..room.y2 {
+        for x in room.x1 + 1..room.x2 {
+            let distance = Pythagoras.distance2d(center_point, rltk::Point::new(x, y));
+            if distance < radius {
+                let idx = level_utils::xy_idx(width, x, y);
+                level_utils::set_tile_to_floor(level, idx as usize);
+            }
+        }
+    }
+}
+
+fn add_horizontal_tunnel(level: &mut Level, x1: i32, x2: i32, y: i32) {
+    let width = level.width as u32;
+    for x in cmp::min(x1, x2)..=cmp::max(x1, x2) {
+        let idx = level_utils::xy_idx(width, x, y);
+        if level.tiles.get(idx).is_some() {
+            level_utils::set_tile_to_floor(level, idx as usize);
+        }
+    }
+}
+
+fn add_vertical_tunnel(level: &mut Level, x: i32, y1: i32, y2: i32) {
+    let width = level.width as u32;
+    for y in cmp::min(y1, y2)..=cmp::max(y1, y2) {
+        let idx = level_utils::xy_idx(width, x, y);
+        if level.tiles.get(idx).is_some() {
+            level_utils::set_tile_to_floor(level, idx as usize);
+        }
+    }
+}
+
+fn add_corridor(level: &mut Level, rng: &mut RandomNumberGenerator, from: Point, to: Point) {
+    if rng.range(0, 2) == 1 {
+        add_horizontal_tunnel(level, from.x, to.x, from.y);
+        add_vertical_tunnel(level, to.x, to.y, from.y);
+    } else {
+        add_vertical_tunnel(level, from.x, from.y, to.y);
+        add_horizontal_tunnel(level, from.x, to.x, to.y);
+    }
+}
+
+// Digs a few extra corridors between random rooms and returns the tiles that were wall before
+// digging, any doors that end up on these tiles are turned into secret doors.
+fn add_hidden_corridors(level: &mut Level, rng: &mut RandomNumberGenerator) -> HashSet<usize> {
+    const MAX_HIDDEN_CORRIDORS: i32 = 2;
+    let mut dug_tiles = HashSet::new();
+    if level.rooms.len() < 3 {
+        return dug_tiles;
+    }
+    let room_count = level.rooms.len() as i32;
+    for _ in 0..rng.range(0, MAX_HIDDEN_CORRIDORS + 1) {
+        let from_room = rng.range(0, room_count) as usize;
+        let to_room = rng.range(0, room_count) as usize;
+        if from_room == to_room {
+            continue;
+        }
+        let from = Point::from(level.rooms[from_room].rect.center());
+        let to = Point::from(level.rooms[to_room].rect.center());
+        let walls_before: Vec<bool> = level.tiles.iter().map(|t| *t == TileType::Wall).collect();
+        add_corridor(level, rng, from, to);
+        for (idx, tile) in level.tiles.iter().enumerate() {
+            if walls_before[idx] && *tile == TileType::Floor {
+                dug_tiles.insert(idx);
+            }
+        }
+    }
+    dug_tiles
+}
+
+// This is the part that needs to determine the locations of door ents.
+fn add_doors_to_rooms(level: &mut Level) {
+    let mut door_idxs: Vec<usize> = Vec::new();
+    let level_width = level.width as u32;
+    for room in level.rooms.iter() {
+        for x in room.rect.x1..=room.rect.x2 {
+            for y in room.rect.y1..=room.rect.y2 {
+                // Checks the left and right walls of a room and adds a door if the tile is empty with a wall above & below
+                if x == room.rect.x1 || x == room.rect.x2 {
+                    if !level_utils::tile_at_xy_is_wall(&level, x, y)
+                        && level_utils::tile_is_between_walls_vertical(&level, x, y)
+                    {
+                        door_idxs.push(level_utils::xy_idx(level_width, x, y));
+                    }
+                }
+
+                // Checks the up and bottom walls of a room and adds a door if the tile is empty with a wall left & right
+                if y == room.rect.y1 || y == room.rect.y2 {
+                    if !level_utils::tile_at_xy_is_wall(&level, x, y)
+                        && level_utils::tile_is_between_walls_horizontal(level, x, y)
+                    {
+                        door_idxs.push(level_utils::xy_idx(level_width, x, y));
+                    }
+                }
+            }
+        }
+    }
+    for idx in door_idxs.iter() {
+        level_utils::set_tile_to_door(level, *idx as usize);
+    }
+}
+
+fn add_secret_doors(level: &mut Level, hidden_corridor_tiles: &HashSet<usize>) {
+    level.secret_doors = level
+        .tiles
+        .iter()
+        .enumerate()
+        .filter(|(idx, tile)| **tile == TileType::Door && hidden_corridor_tiles.contains(idx))
+        .map(|(idx, _)| idx)
+        .collect();
+}
+
+// Shared by the builders that produce rooms, once the rooms are carved and joined up this adds
+// the doors, secret passages and room features.
+fn add_room_details(level: &mut Level, rng: &mut RandomNumberGenerator) {
+    let hidden_corridor_tiles = add_hidden_corridors(level, rng);
+    add_doors_to_rooms(level);
+    add_secret_doors(level, &hidden_corridor_tiles);
+    update_level_from_room_features(level, rng);
+}
+
+// Secret doors are left as doors until decorating is done so that furniture isn't placed in front
+// of them, after that they are disguised as wall until they are found.
+fn hide_secret_doors(level: &mut Level) {
+    for idx in level.secret_doors.iter() {
+        level.tiles[*idx] = TileType::Wall;
+    }
+}
+
+fn make_rect_square(rect: &mut Rect) {
+    let size_height = rect.y2 - rect.y1;
+    let size_width = rect.x2 - rect.x1;
+    let smallest_side = i32::min(size_width, size_height);
+    rect.x1 = rect.x1 + (size_width - smallest_side) / 2;
+    rect.x2 = rect.x1 + smallest_side;
+    rect.y1 = rect.y1 + (size_height - smallest_side) / 2;
+    rect.y2 = rect.y1 + smallest_side;
+}
+
+// Levels without rooms can put the way in on any floor tile.
+fn get_entrance_idx(level: &Level, rng: &mut RandomNumberGenerator) -> usize {
+    match level.rooms.first() {
+        Some(room) => level_utils::get_random_spawn_point(&room.rect, level, rng),
+        None => level_utils::get_random_unblocked_floor_point(level, rng).unwrap(),
+    }
+}
+
+// Levels without rooms put the way down on the floor tile furthest from the way in.
+fn get_down_stairs_idx(level: &Level, rng: &mut RandomNumberGenerator) -> usize {
+    if let Some(room) = level.rooms.last() {
+        return level_utils::get_random_spawn_point(&room.rect, level, rng);
+    }
+    match level.stairs_up.or(level.exit) {
+        Some(entrance_idx) => level
+            .tiles
+            .iter()
+            .enumerate()
+            .filter(|(_, tile)| **tile == TileType::Floor)
+            .map(|(idx, _)| idx)
+            .max_by(|a, b| {
+                let distance_a = level_utils::get_distance_between_idxs(level, entrance_idx, *a);
+                let distance_b = level_utils::get_distance_between_idxs(level, entrance_idx, *b);
+                distance_a.partial_cmp(&distance_b).unwrap()
+            })
+            .unwrap(),
+        None => level_utils::get_random_unblocked_floor_point(level, rng).unwrap(),
+    }
+}
+
+fn add_exit(level: &mut Level, rng: &mut RandomNumberGenerator) {
+    let exit_idx = get_entrance_idx(level, rng);
+    level.tiles[exit_idx] = TileType::Exit;
+    level.exit = Some(exit_idx);
+}
+
+fn add_down_stairs(level: &mut Level, rng: &mut RandomNumberGenerator) {
+    let stairs_idx = get_down_stairs_idx(level, rng);
+    level.tiles[stairs_idx] = TileType::DownStairs;
+    level.stairs_down = Some(stairs_idx);
+}
+
+fn add_up_stairs(level: &mut Level, rng: &mut RandomNumberGenerator) {
+    let stairs_idx = get_entrance_idx(level, rng);
+    level.tiles[stairs_idx] = TileType::UpStairs;
+    level.stairs_up = Some(stairs_idx);
+}
+
+// Walls off every floor tile that can't be reached from the largest open area, used by builders
+// that carve their layout at random and can leave isolated pockets behind.
+fn remove_unreachable_areas(level: &mut Level) {
+    let mut unvisited: HashSet<usize> = level
+        .tiles
+        .iter()
+        .enumerate()
+        .filter(|(_, tile)| **tile == TileType::Floor)
+        .map(|(idx, _)| idx)
+        .collect();
+    let mut largest_area: HashSet<usize> = HashSet::new();
+    while let Some(start_idx) = unvisited.iter().next().cloned() {
+        let area = level_utils::get_connected_tiles(level, start_idx, |idx| {
+            level.tiles[idx] == TileType::Floor
+        });
+        unvisited.retain(|idx| !area.contains(idx));
+        if area.len() > largest_area.len() {
+            largest_area = area;
+        }
+    }
+    for idx in 0..level.tiles.len() {
+        if level.tiles[idx] == TileType::Floor && !largest_area.contains(&idx) {
+            level.tiles[idx] = TileType::Wall;
+        }
+    }
+}
+
+fn add_branch_stairs(level: &mut Level, rng: &mut RandomNumberGenerator) {
+    for branch in level.id.get_branches_entered_from() {
+        // vaults are stamped over the middle of their rooms later on, so the stairs stay out of them
+        let room_rects: Vec<Rect> = level
+            .rooms
+            .iter()
+            .filter(|room| room.vault.is_none())
+            .map(|room| room.rect)
+            .collect();
+        let stairs_idx = match room_rects.len() {
+            0 => level_utils::get_random_unblocked_floor_point(level, rng).unwrap(),
+            room_count => {
+                let rect = room_rects[rng.range(0, room_count)];
+                level_utils::get_random_spawn_point(&rect, level, rng)
+            }
+        };
+        level.tiles[stairs_idx] = TileType::DownStairs;
+        level.branch_stairs.push((stairs_idx, branch));
+    }
+}
+
+pub fn get_builder_for_level(
+    id: LevelId,
+    rng: &mut RandomNumberGenerator,
+) -> Box<dyn LevelBuilder> {
+    match id.branch {
+        Branch::FloodedCistern => return Box::new(FloodedCisternBuilder {}),
+        Branch::GoblinWarren => return Box::new(DrunkardsWalkBuilder {}),
+        Branch::Main => {}
+    }
+    if id.is_top_floor() {
+        return Box::new(RoomsAndCorridorsBuilder {});
+    }
+    match id.depth {
+        0..=2 => match rng.range(0, 2) {
+            0 => Box::new(CellularAutomataBuilder {}),
+            _ => Box::new(DrunkardsWalkBuilder {}),
+        },
+        3..=5 => match rng.range(0, 3) {
+            0 => Box::new(RoomsAndCorridorsBuilder {}),
+            _ => Box::new(BspFortressBuilder {}),
+        },
+        _ => Box::new(RoomsAndCorridorsBuilder {}),
+    }
+}
+
+fn build_level(
+    builder: &mut dyn LevelBuilder,
+    id: LevelId,
+    rng: &mut RandomNumberGenerator,
+) -> Level {
+    let mut level = Level::new(id);
+    builder.build_layout(&mut level, rng);
+    if !id.is_top_floor() {
+        add_up_stairs(&mut level, rng);
+    } else {
+        add_exit(&mut level, rng);
+    }
+    if !id.is_bottom_floor() {
+        add_down_stairs(&mut level, rng);
+    }
+    add_branch_stairs(&mut level, rng);
+    update_room_stamps_from_level(&mut level);
+    vaults::stamp_vaults_onto_rooms(&mut level);
+    decorate_level(&mut level, rng);
+    update_level_from_room_stamps(&mut level);
+    hide_secret_doors(&mut level);
+    level_utils::populate_blocked(&mut level);
+    level_utils::populate_opaque(&mut level);
+    level
+}
+
+// Levels that can't be fully traversed are repaired, and if that doesn't work they are rebuilt.
+// When every attempt fails the last repaired level is handed back along with what's unreachable.
+fn try_build_validated_level(
+    builder: &mut dyn LevelBuilder,
+    id: LevelId,
+    rng: &mut RandomNumberGenerator,
+) -> Result<Level, (Level, Vec<UnreachableTarget>)> {
+    let mut level = build_level(builder, id, rng);
+    for attempt in 1..=MAX_BUILD_ATTEMPTS {
+        if let Err(unreachable) = validate_level(&level) {
+            repair_level(&mut level, &unreachable);
+        }
+        match validate_level(&level) {
+            Ok(()) => return Ok(level),
+            Err(unreachable) if attempt == MAX_BUILD_ATTEMPTS => return Err((level, unreachable)),
+            Err(_) => level = build_level(builder, id, rng),
+        }
+    }
+    unreachable!()
+}
+
+// A builder that keeps failing shouldn't stop the game, rooms and corridors connects every room it
+// lays out so it's used in its place.
+fn build_validated_level(
+    builder: &mut dyn LevelBuilder,
+    id: LevelId,
+    rng: &mut RandomNumberGenerator,
+) -> Level {
+    match try_build_validated_level(builder, id, rng) {
+        Ok(level) => level,
+        Err((_, unreachable)) => {
+            rltk::console::log(format!(
+                "could not build a valid level for {}, unreachable: {:?}, falling back to rooms and corridors",
+                id.get_name(),
+                unreachable
+            ));
+            match try_build_validated_level(&mut RoomsAndCorridorsBuilder {}, id, rng) {
+                Ok(level) => level,
+                Err((level, unreachable)) => {
+                    rltk::console::log(format!(
+                        "fallback level for {} is still invalid, unreachable: {:?}",
+                        id.get_name(),
+                        unreachable
+                    ));
+                    level
+                }
+            }
+        }
+    }
+}
+
+pub fn build(builder: &mut dyn LevelBuilder, id: LevelId) -> Level {
+    build_validated_level(builder, id, &mut RandomNumberGenerator::new())
+}
+
+// The same seed always produces the same level, which makes it possible to check many levels for
+// problems and to rebuild any level that turns up.
+pub fn build_from_seed(builder: &mut dyn LevelBuilder, id: LevelId, seed: u64) -> Level {
+    build_validated_level(builder, id, &mut RandomNumberGenerator::seeded(seed))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SEED_COUNT: u64 = 20;
+
+    fn get_all_builders() -> Vec<Box<dyn LevelBuilder>> {
+        vec![
+            Box::new(BspFortressBuilder {}),
+            Box::new(CellularAutomataBuilder {}),
+            Box::new(DrunkardsWalkBuilder {}),
+            Box::new(FloodedCisternBuilder {}),
+            Box::new(RoomsAndCorridorsBuilder {}),
+        ]
+    }
+
+    fn get_all_level_ids() -> Vec<LevelId> {
+        [Branch::Main, Branch::FloodedCistern, Branch::GoblinWarren]
+            .iter()
+            .map(|branch| branch.get_level_ids())
+            .flatten()
+            .collect()
+    }
+
+    #[test]
+    fn every_builder_produces_valid_levels() {
+        for mut builder in get_all_builders() {
+            for id in get_all_level_ids() {
+                for seed in 0..SEED_COUNT {
+                    let mut rng = RandomNumberGenerator::seeded(seed);
+                    // skips the fallback builder so a failing builder isn't hidden
+                    let level = try_build_validated_level(builder.as_mut(), id, &mut rng);
+                    assert!(
+                        level.is_ok(),
+                        "invalid level for {} with seed {}",
+                        id.get_name(),
+                        seed
+                    );
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,97 @@
+use super::{
+    add_circular_room, add_corridor, add_rectangular_room, add_room_details, make_rect_square,
+    LevelBuilder,
+};
+use crate::dungeon::{level::Level, rect::Rect, room::Room, vaults};
+use crate::utils::get_x_random_elements;
+use rltk::{DistanceAlg::Pythagoras, Point, RandomNumberGenerator};
+use std::cmp;
+use std::collections::HashMap;
+
+// The original builder, splits the level up into rects, turns some of them into rooms and joins
+// each room to its nearest neighbours.
+pub struct RoomsAndCorridorsBuilder {}
+
+fn generate_rects_for_level(
+    level_width: i32,
+    level_height: i32,
+    rng: &mut RandomNumberGenerator,
+) -> Vec<Rect> {
+    const MIN_ROOM_SIZE: i32 = 5;
+    let mut rects = Vec::new();
+    rects.push(Rect::new(1, 1, level_width - 3, level_height - 3));
+    for _ in 0..100 {
+        let random_index = rng.range(0, rects.len() as i32);
+        let rect = rects[random_index as usize];
+        let width = rect.x2 - rect.x1;
+        let height = rect.y2 - rect.y1;
+        if width > MIN_ROOM_SIZE * 2 && width > height {
+            let x = cmp::max(
+                rect.x1 + MIN_ROOM_SIZE,
+                rng.range(rect.x1, rect.x2 - MIN_ROOM_SIZE),
+            );
+            rects.remove(random_index as usize);
+            rects.push(Rect::new(rect.x1, rect.y1, x - rect.x1, rect.y2 - rect.y1));
+            rects.push(Rect::new(x, rect.y1, rect.x2 - x, rect.y2 - rect.y1));
+        } else if height > MIN_ROOM_SIZE * 2 && height > width {
+            let y = cmp::max(
+                rect.y1 + MIN_ROOM_SIZE,
+                rng.range(rect.y1, rect.y2 - MIN_ROOM_SIZE),
+            );
+            rects.remove(random_index as usize);
+            rects.push(Rect::new(rect.x1, rect.y1, rect.x2 - rect.x1, y - rect.y1));
+            rects.push(Rect::new(rect.x1, y, rect.x2 - rect.x1, rect.y2 - y));
+        }
+    }
+    rects
+}
+
+fn add_nearest_neighbor_corridors(level: &mut Level, rng: &mut RandomNumberGenerator) {
+    let mut connected: HashMap<usize, Vec<(Point, Point)>> = HashMap::new();
+    for (i, room) in level.rooms.iter().enumerate() {
+        let room_center_point = Point::from(room.rect.center());
+        let mut room_distance: Vec<(usize, f32, Point)> = level
+            .rooms
+            .iter()
+            .enumerate()
+            .filter(|(j, _)| &i != j && !connected.contains_key(&j))
+            .map(|(j, other_room)| {
+                let other_room_center_point = Point::from(other_room.rect.center());
+                let distance = Pythagoras.distance2d(room_center_point, other_room_center_point);
+                (j, distance, other_room_center_point)
+            })
+            .collect();
+        room_distance.sort_by(|a, b| a.1.partial_cmp(&b.1).unwrap());
+
+        let points: Vec<(Point, Point)> = room_distance
+            .iter()
+            .take(2)
+            .map(|(_, __, other_room_center_point)| (room_center_point, *other_room_center_point))
+            .collect();
+        connected.insert(i, points);
+    }
+    for points_pairs in connected.values() {
+        for point_pair in points_pairs {
+            add_corridor(level, rng, point_pair.0, point_pair.1);
+        }
+    }
+}
+
+impl LevelBuilder for RoomsAndCorridorsBuilder {
+    fn build_layout(&mut self, level: &mut Level, rng: &mut RandomNumberGenerator) {
+        let mut rects = generate_rects_for_level(level.width as i32, level.height as i32, rng);
+        let room_count = rng.range(2, rects.len() as i32);
+        let mut room_rects = get_x_random_elements(rng, room_count as u32, &mut rects);
+        room_rects.iter_mut().for_each(|r| match rng.range(0, 6) {
+            1 => {
+                make_rect_square(r);
+                add_circular_room(level, r)
+            }
+            _ => add_rectangular_room(level, r),
+        });
+        level.rooms = room_rects.iter().map(|r| Room::new(*r, rng)).collect();
+        vaults::assign_vaults_to_rooms(level, rng);
+        add_nearest_neighbor_corridors(level, rng);
+        add_room_details(level, rng);
+    }
+}
@@ -0,0 +1,192 @@
+use crate::dungeon::{
+    branch::Branch, level::Level, level_utils, room_decorators::RoomPart, tile_type::TileType,
+};
+use rltk::Point;
+use stamp_rs::StampPart::Use;
+use std::cmp;
+use std::collections::HashSet;
+
+#[derive(PartialEq, Clone, Copy, Debug)]
+pub enum UnreachableTarget {
+    StairsDown(usize),
+    BranchStairs(usize, Branch),
+    Room(usize),
+    SpawnPoint(usize),
+}
+
+impl UnreachableTarget {
+    fn get_idx(&self, level: &Level) -> usize {
+        match self {
+            Self::StairsDown(idx) | Self::BranchStairs(idx, _) | Self::SpawnPoint(idx) => *idx,
+            Self::Room(room_index) => {
+                let (x, y) = level.rooms[*room_index].rect.center();
+                level_utils::xy_idx(level.width as u32, x, y)
+            }
+        }
+    }
+}
+
+// Furniture isn't spawned until after the level is built, so the room stamps are the only place
+// to find out where it will be.
+fn get_furniture_idxs(level: &Level) -> HashSet<usize> {
+    let mut furniture_idxs = HashSet::new();
+    for room in level.rooms.iter() {
+        for (y, row) in room.stamp.pattern.iter().enumerate() {
+            for (x, part) in row.iter().enumerate() {
+                if let Use(room_part) = part {
+                    if room_part.blocks_tile() {
+                        let this_x = room.rect.x1 + x as i32;
+                        let this_y = room.rect.y1 + y as i32;
+                        furniture_idxs.insert(level_utils::xy_idx(
+                            level.width as u32,
+                            this_x,
+                            this_y,
+                        ));
+                    }
+                }
+            }
+        }
+    }
+    furniture_idxs
+}
+
+// Closed doors can be opened and secret doors can be found, so neither counts as blocking.
+fn is_traversable(level: &Level, furniture_idxs: &HashSet<usize>, idx: usize) -> bool {
+    (!level.blocked[idx] || level.tiles[idx] == TileType::Door || level.secret_doors.contains(&idx))
+        && !furniture_idxs.contains(&idx)
+}
+
+// The objective, along with everything else spawned into the level, is placed on one of these
+// tiles. Levels with rooms spawn inside them, levels without rooms spawn on any open floor.
+fn get_spawn_points(level: &Level, furniture_idxs: &HashSet<usize>) -> Vec<usize> {
+    let spawn_points = match level.rooms.len() {
+        0 => (0..level.tiles.len())
+            .filter(|idx| level.tiles[*idx] == TileType::Floor && !level.blocked[*idx])
+            .collect(),
+        _ => level
+            .rooms
+            .iter()
+            .map(|room| level_utils::get_walkable_tiles_in_rect(&room.rect, level))
+            .flatten()
+            .collect(),
+    };
+    level_utils::filter_water_from_tiles(spawn_points, level)
+        .into_iter()
+        .filter(|idx| !furniture_idxs.contains(idx))
+        .collect()
+}
+
+fn get_reachable_tiles_avoiding(level: &Level, furniture_idxs: &HashSet<usize>) -> HashSet<usize> {
+    match level.stairs_up.or(level.exit) {
+        Some(entrance_idx) => level_utils::get_connected_tiles(level, entrance_idx, |idx| {
+            is_traversable(level, furniture_idxs, idx)
+        }),
+        None => HashSet::new(),
+    }
+}
+
+pub fn get_reachable_tiles(level: &Level) -> HashSet<usize> {
+    get_reachable_tiles_avoiding(level, &get_furniture_idxs(level))
+}
+
+// Checks that the down stairs, any branch stairs, every room and every tile the objective could be
+// spawned on can be reached from the way into the level.
+pub fn validate_level(level: &Level) -> Result<(), Vec<UnreachableTarget>> {
+    let furniture_idxs = get_furniture_idxs(level);
+    let reachable = get_reachable_tiles_avoiding(level, &furniture_idxs);
+    let mut unreachable = vec![];
+    if let Some(idx) = level.stairs_down {
+        if !reachable.contains(&idx) {
+            unreachable.push(UnreachableTarget::StairsDown(idx));
+        }
+    }
+    for (idx, branch) in level.branch_stairs.iter() {
+        if !reachable.contains(idx) {
+            unreachable.push(UnreachableTarget::BranchStairs(*idx, *branch));
+        }
+    }
+    for (room_index, room) in level.rooms.iter().enumerate() {
+        let room_is_reachable = level_utils::get_walkable_tiles_in_rect(&room.rect, level)
+            .iter()
+            .any(|idx| reachable.contains(idx));
+        if !room_is_reachable {
+            unreachable.push(UnreachableTarget::Room(room_index));
+        }
+    }
+    for idx in get_spawn_points(level, &furniture_idxs) {
+        if !reachable.contains(&idx) {
+            unreachable.push(UnreachableTarget::SpawnPoint(idx));
+        }
+    }
+    match unreachable.len() {
+        0 => Ok(()),
+        _ => Err(unreachable),
+    }
+}
+
+fn clear_tile(level: &mut Level, idx: usize) {
+    let (x, y) = level_utils::idx_xy(level.width as u32, idx);
+    match level.tiles[idx] {
+        // secret doors are still doors in the room stamps, so they just stop being secret
+        TileType::Wall if level.secret_doors.contains(&idx) => {
+            level.secret_doors.retain(|secret_idx| *secret_idx != idx);
+            level.tiles[idx] = TileType::Door;
+        }
+        TileType::Wall | TileType::Column | TileType::Ledge | TileType::WaterDeep => {
+            level_utils::set_tile_to_floor(level, idx);
+        }
+        _ => {}
+    }
+    for room in level.rooms.iter_mut() {
+        if !room.rect.contains(x, y) {
+            continue;
+        }
+        let room_xy = ((x - room.rect.x1) as usize, (y - room.rect.y1) as usize);
+        let blocks_tile = match room.stamp.get_at(room_xy) {
+            Some(Use(room_part)) => room_part.blocks_tile(),
+            _ => false,
+        };
+        if blocks_tile {
+            room.stamp.set_at(room_xy, Use(RoomPart::Floor));
+        }
+    }
+}
+
+fn carve_path(level: &mut Level, from: Point, to: Point) {
+    let width = level.width as u32;
+    for x in cmp::min(from.x, to.x)..=cmp::max(from.x, to.x) {
+        clear_tile(level, level_utils::xy_idx(width, x, from.y));
+    }
+    for y in cmp::min(from.y, to.y)..=cmp::max(from.y, to.y) {
+        clear_tile(level, level_utils::xy_idx(width, to.x, y));
+    }
+}
+
+// Carves a path from each unreachable target to the closest tile that can be reached, knocking
+// through walls and clearing furniture out of the way.
+pub fn repair_level(level: &mut Level, unreachable: &Vec<UnreachableTarget>) {
+    let reachable = get_reachable_tiles(level);
+    if reachable.is_empty() {
+        return;
+    }
+    let width = level.width as u32;
+    for target in unreachable.iter() {
+        let target_idx = target.get_idx(level);
+        let closest_idx = reachable
+            .iter()
+            .min_by(|a, b| {
+                let distance_a = level_utils::get_distance_between_idxs(level, target_idx, **a);
+                let distance_b = level_utils::get_distance_between_idxs(level, target_idx, **b);
+                distance_a.partial_cmp(&distance_b).unwrap()
+            })
+            .cloned()
+            .unwrap();
+        carve_path(
+            level,
+            level_utils::idx_point(width, target_idx),
+            level_utils::idx_point(width, closest_idx),
+        );
+    }
+    level_utils::populate_blocked(level);
+    level_utils::populate_opaque(level);
+}
@@ -0,0 +1,77 @@
+use super::branch::{Branch, SIDE_BRANCHES};
+use serde::{Deserialize, Serialize};
+
+// saves from before there were branches only stored the depth of the level
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum SavedLevelId {
+    Depth(u8),
+    Id { branch: Branch, depth: u8 },
+}
+
+impl From<SavedLevelId> for LevelId {
+    fn from(saved: SavedLevelId) -> Self {
+        match saved {
+            SavedLevelId::Depth(depth) => Self::new(Branch::Main, depth),
+            SavedLevelId::Id { branch, depth } => Self::new(branch, depth),
+        }
+    }
+}
+
+#[derive(Default, PartialEq, Eq, Hash, Serialize, Deserialize, Clone, Copy, Debug)]
+#[serde(from = "SavedLevelId")]
+pub struct LevelId {
+    pub branch: Branch,
+    pub depth: u8,
+}
+
+impl LevelId {
+    pub fn new(branch: Branch, depth: u8) -> Self {
+        Self { branch, depth }
+    }
+
+    pub fn get_name(&self) -> String {
+        format!("{}: {}", self.branch.get_name(), self.depth)
+    }
+
+    // Floors count up from 1 at the top of the dungeon, the reverse of depth.
+    pub fn get_floor(&self) -> u8 {
+        Branch::Main.get_top_depth() + 1 - self.depth
+    }
+
+    // Only the top of the main branch leads out of the dungeon.
+    pub fn is_top_floor(&self) -> bool {
+        self.branch == Branch::Main && self.depth == self.branch.get_top_depth()
+    }
+
+    pub fn is_bottom_floor(&self) -> bool {
+        self.depth == self.branch.get_bottom_depth()
+    }
+
+    pub fn get_level_below(&self) -> Option<LevelId> {
+        match self.is_bottom_floor() {
+            true => None,
+            false => Some(LevelId::new(self.branch, self.depth - 1)),
+        }
+    }
+
+    // The top of a side branch leads back up to the main level that holds its entrance.
+    pub fn get_level_above(&self) -> Option<LevelId> {
+        if self.depth < self.branch.get_top_depth() {
+            return Some(LevelId::new(self.branch, self.depth + 1));
+        }
+        self.branch
+            .get_entrance_depth()
+            .map(|depth| LevelId::new(Branch::Main, depth))
+    }
+
+    pub fn get_branches_entered_from(&self) -> Vec<Branch> {
+        SIDE_BRANCHES
+            .iter()
+            .filter(|branch| {
+                self.branch == Branch::Main && branch.get_entrance_depth() == Some(self.depth)
+            })
+            .cloned()
+            .collect()
+    }
+}
@@ -237,3 +237,28 @@ pub fn get_neighbors_for_idx(level_width: i32, idx: i32) -> [i32; 8] {
         idx - 1 + level_width,
     ]
 }
+
+pub fn get_connected_tiles<F>(level: &Level, start_idx: usize, is_passable: F) -> HashSet<usize>
+where
+    F: Fn(usize) -> bool,
+{
+    let mut connected = HashSet::new();
+    if idx_not_in_map(level, start_idx) || !is_passable(start_idx) {
+        return connected;
+    }
+    connected.insert(start_idx);
+    let mut frontier = vec![start_idx];
+    while let Some(idx) = frontier.pop() {
+        for neighbor in get_neighbors_for_idx(level.width as i32, idx as i32).iter() {
+            if *neighbor < 0 || idx_not_in_map(level, *neighbor as usize) {
+                continue;
+            }
+            let neighbor = *neighbor as usize;
+            if !connected.contains(&neighbor) && is_passable(neighbor) {
+                connected.insert(neighbor);
+                frontier.push(neighbor);
+            }
+        }
+    }
+    connected
+}
@@ -1,11 +1,15 @@
+pub mod branch;
 pub mod column_placers;
 pub mod constants;
 pub mod dungeon;
 pub mod level;
 pub mod level_builders;
+pub mod level_id;
 pub mod level_utils;
 pub mod rect;
+pub mod remembered_entity;
 pub mod room;
 pub mod room_decorators;
 pub mod room_feature;
 pub mod tile_type;
+pub mod vaults;
@@ -0,0 +1,20 @@
+use rltk::RGB;
+use serde::{Deserialize, Serialize};
+use specs::Entity;
+
+// what the player last saw of an entity, kept on the level once it leaves view
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct RememberedEntity {
+    pub idx: usize,
+    pub name: String,
+    pub glyph: u16,
+    pub fg: RGB,
+    pub layer: i32,
+    // items and containers are worth travelling back to
+    #[serde(default)]
+    pub is_landmark: bool,
+    // entities are not stable across a save, so after loading this is only
+    // known again once the entity is seen
+    #[serde(skip)]
+    pub entity: Option<Entity>,
+}
@@ -14,11 +14,13 @@ pub struct Room {
     pub room_type: Option<RoomType>,
     pub stamp: Stamp<StampPart<RoomPart>>,
     pub features: Vec<Option<RoomFeature>>,
+    // only needed while the level is being built
+    #[serde(skip)]
+    pub vault: Option<Stamp<StampPart<RoomPart>>>,
 }
 
 impl Room {
-    pub fn new(rect: Rect) -> Self {
-        let mut rng = RandomNumberGenerator::new();
+    pub fn new(rect: Rect, rng: &mut RandomNumberGenerator) -> Self {
         let room_type = match rect.area() {
             0..=8 => None,
             9..=75 => {
@@ -31,7 +33,7 @@ impl Room {
                     Some(RoomType::Kitchen),
                     None,
                 ];
-                get_random_element(&mut rng, &choices).to_owned()
+                get_random_element(rng, &choices).to_owned()
             }
             76..=100 => {
                 let choices = vec![
@@ -41,7 +43,7 @@ impl Room {
                     Some(RoomType::DiningRoom),
                     None,
                 ];
-                get_random_element(&mut rng, &choices).to_owned()
+                get_random_element(rng, &choices).to_owned()
             }
             101..=200 => {
                 let mut choices = vec![Some(RoomType::MessHall), Some(RoomType::Barracks), None];
@@ -49,7 +51,7 @@ impl Room {
                     choices.push(Some(RoomType::ClassRoom));
                     choices.push(Some(RoomType::MeetingRoom));
                 }
-                get_random_element(&mut rng, &choices).to_owned()
+                get_random_element(rng, &choices).to_owned()
             }
             _ => {
                 let choices = vec![
@@ -61,7 +63,7 @@ impl Room {
                     Some(RoomType::Baths),
                     Some(RoomType::ThroneRoom),
                 ];
-                get_random_element(&mut rng, &choices).to_owned()
+                get_random_element(rng, &choices).to_owned()
             }
         };
         let mut features = vec![];
@@ -86,7 +88,7 @@ impl Room {
                     Some(RoomFeature::ColumnsDoubleBottom),
                     None,
                 ];
-                features.push(get_random_element(&mut rng, &column_choices).to_owned());
+                features.push(get_random_element(rng, &column_choices).to_owned());
             }
             Some(RoomType::Barracks) => {
                 let column_choices = vec![
@@ -101,7 +103,7 @@ impl Room {
                     Some(RoomFeature::ColumnsSingleBottom),
                     None,
                 ];
-                features.push(get_random_element(&mut rng, &column_choices).to_owned());
+                features.push(get_random_element(rng, &column_choices).to_owned());
             }
             Some(RoomType::BedRoom) | Some(RoomType::SittingRoom) | Some(RoomType::DiningRoom) => {
                 let column_choices = vec![
@@ -111,7 +113,7 @@ impl Room {
                     Some(RoomFeature::ColumnsSingleBottom),
                     None,
                 ];
-                features.push(get_random_element(&mut rng, &column_choices).to_owned());
+                features.push(get_random_element(rng, &column_choices).to_owned());
             }
             Some(RoomType::ThroneRoom) => {
                 let column_choices = vec![
@@ -122,7 +124,7 @@ impl Room {
                     Some(RoomFeature::ColumnsTripleHorizontal),
                     Some(RoomFeature::ColumnsTripleAll),
                 ];
-                features.push(get_random_element(&mut rng, &column_choices).to_owned());
+                features.push(get_random_element(rng, &column_choices).to_owned());
             }
             Some(RoomType::Courtyard) | Some(RoomType::Baths) => {
                 let column_choices = vec![
@@ -133,13 +135,13 @@ impl Room {
                     Some(RoomFeature::ColumnsTripleHorizontal),
                     Some(RoomFeature::ColumnsTripleAll),
                 ];
-                features.push(get_random_element(&mut rng, &column_choices).to_owned());
+                features.push(get_random_element(rng, &column_choices).to_owned());
                 let middle_column_choices = vec![
                     Some(RoomFeature::ColumnsDoubleMiddle),
                     Some(RoomFeature::ColumnsSingleMiddle),
                     None,
                 ];
-                features.push(get_random_element(&mut rng, &middle_column_choices).to_owned());
+                features.push(get_random_element(rng, &middle_column_choices).to_owned());
             }
             _ => {}
         };
@@ -156,6 +158,7 @@ impl Room {
             room_type,
             stamp,
             features,
+            vault: None,
         }
     }
 }
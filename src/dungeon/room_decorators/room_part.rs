@@ -29,5 +29,34 @@ pub enum RoomPart {
     TowelRack = 24,
     Throne = 25,
     Podium = 26,
-    Sconce = 27
+    Sconce = 27,
+    MonsterSpawn = 28,
+    ItemSpawn = 29,
+}
+
+impl RoomPart {
+    // Parts that are spawned as furniture or debris, which block the tile they are placed on.
+    pub fn blocks_tile(&self) -> bool {
+        match self {
+            Self::Bed
+            | Self::Armoire
+            | Self::Dresser
+            | Self::BedsideTable
+            | Self::Chest
+            | Self::Chair
+            | Self::Desk
+            | Self::Shelf
+            | Self::Table
+            | Self::Stove
+            | Self::Cupboard
+            | Self::Counter
+            | Self::Barrel
+            | Self::WeaponRack
+            | Self::Debris
+            | Self::TowelRack
+            | Self::Throne
+            | Self::Podium => true,
+            _ => false,
+        }
+    }
 }
@@ -12,3 +12,19 @@ pub enum TileType {
   Ledge,
   WaterDeep
 }
+
+impl TileType {
+  pub fn get_name(&self) -> &'static str {
+    match self {
+      TileType::Wall => "Wall",
+      TileType::Column => "Column",
+      TileType::Floor => "Floor",
+      TileType::Door => "Doorway",
+      TileType::DownStairs => "Stairs leading down",
+      TileType::UpStairs => "Stairs leading up",
+      TileType::Exit => "Dungeon exit",
+      TileType::Ledge => "Ledge",
+      TileType::WaterDeep => "Deep water",
+    }
+  }
+}
@@ -0,0 +1,178 @@
+// Vaults are hand authored set piece rooms. Each template is an ascii pattern where each character
+// maps to a RoomPart, characters that don't map to anything are left transparent so that the
+// underlying room shows through. Templates live in vaults.ron.
+use super::{level::Level, room::Room, room_decorators::RoomPart};
+use crate::utils::get_random_element;
+use rltk::RandomNumberGenerator;
+use ron::from_str;
+use serde::{Deserialize, Serialize};
+use stamp_rs::{
+    Stamp, StampPart,
+    StampPart::{Transparent, Use},
+};
+use std::collections::HashMap;
+
+const VAULT_TEMPLATES_STRING: &str = include_str!("./vaults.ron");
+const MAX_VAULTS_PER_LEVEL: usize = 2;
+
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct VaultTemplate {
+    pub name: String,
+    pub min_floor: u8,
+    pub max_floor: u8,
+    // a template has a 1 in `rarity` chance of being considered for any given level
+    pub rarity: i32,
+    #[serde(default)]
+    pub legend: HashMap<char, RoomPart>,
+    pub pattern: Vec<String>,
+}
+
+fn get_default_part_for_char(character: char) -> Option<RoomPart> {
+    match character {
+        '#' => Some(RoomPart::Wall),
+        '.' => Some(RoomPart::Floor),
+        '+' => Some(RoomPart::Door),
+        'o' => Some(RoomPart::Column),
+        '=' => Some(RoomPart::Ledge),
+        '~' => Some(RoomPart::WaterDeep),
+        'a' => Some(RoomPart::Armoire),
+        'b' => Some(RoomPart::Bed),
+        'B' => Some(RoomPart::Barrel),
+        'c' => Some(RoomPart::Chair),
+        'C' => Some(RoomPart::Chest),
+        'd' => Some(RoomPart::Desk),
+        'P' => Some(RoomPart::Podium),
+        's' => Some(RoomPart::Shelf),
+        't' => Some(RoomPart::Table),
+        'T' => Some(RoomPart::Throne),
+        'W' => Some(RoomPart::WeaponRack),
+        'x' => Some(RoomPart::Debris),
+        '*' => Some(RoomPart::Sconce),
+        'g' => Some(RoomPart::MonsterSpawn),
+        'i' => Some(RoomPart::ItemSpawn),
+        _ => None,
+    }
+}
+
+impl VaultTemplate {
+    fn get_part_for_char(&self, character: char) -> StampPart<RoomPart> {
+        let part = match self.legend.get(&character) {
+            Some(part) => Some(*part),
+            None => get_default_part_for_char(character),
+        };
+        match part {
+            Some(part) => Use(part),
+            None => Transparent,
+        }
+    }
+
+    pub fn to_stamp(&self) -> Stamp<StampPart<RoomPart>> {
+        let width = self
+            .pattern
+            .iter()
+            .map(|row| row.chars().count())
+            .max()
+            .unwrap_or(0);
+        Stamp::new(
+            self.pattern
+                .iter()
+                .map(|row| {
+                    let mut parts: Vec<StampPart<RoomPart>> =
+                        row.chars().map(|c| self.get_part_for_char(c)).collect();
+                    parts.resize(width, Transparent);
+                    parts
+                })
+                .collect(),
+        )
+    }
+
+    pub fn is_available_on_floor(&self, floor: u8) -> bool {
+        floor >= self.min_floor && floor <= self.max_floor
+    }
+}
+
+pub fn get_vault_templates() -> Vec<VaultTemplate> {
+    from_str::<Vec<VaultTemplate>>(VAULT_TEMPLATES_STRING).expect("failed to parse vaults.ron")
+}
+
+// every rotation of the stamp, and every rotation of its mirror image.
+fn get_orientations(stamp: &Stamp<StampPart<RoomPart>>) -> Vec<Stamp<StampPart<RoomPart>>> {
+    let mut mirrored = stamp.clone();
+    mirrored.flip_horizontal();
+    vec![stamp.clone(), mirrored]
+        .iter()
+        .map(|base| {
+            (0..4)
+                .map(|turns| {
+                    let mut rotated = base.clone();
+                    for _ in 0..turns {
+                        rotated.rotate_90();
+                    }
+                    rotated
+                })
+                .collect::<Vec<Stamp<StampPart<RoomPart>>>>()
+        })
+        .flatten()
+        .collect()
+}
+
+// The room stamp includes the walls of the room, and the vault needs a tile of floor between it and
+// those walls so that any doors in the vault can be reached.
+fn vault_fits_in_room(vault_stamp: &Stamp<StampPart<RoomPart>>, room: &Room) -> bool {
+    vault_stamp.width() + 4 <= room.stamp.width() && vault_stamp.height() + 4 <= room.stamp.height()
+}
+
+pub fn assign_vaults_to_rooms(level: &mut Level, rng: &mut RandomNumberGenerator) {
+    let room_count = level.rooms.len();
+    if room_count < 3 {
+        return;
+    }
+    let floor = level.id.get_floor();
+    let mut vault_count = 0;
+    for template in get_vault_templates()
+        .iter()
+        .filter(|t| t.is_available_on_floor(floor))
+    {
+        if vault_count >= MAX_VAULTS_PER_LEVEL {
+            break;
+        }
+        if rng.range(0, template.rarity) != 0 {
+            continue;
+        }
+        let orientations = get_orientations(&template.to_stamp());
+        // the first and last rooms get the stairs and exit, so they're skipped.
+        let candidates: Vec<(usize, Stamp<StampPart<RoomPart>>)> = level
+            .rooms
+            .iter()
+            .enumerate()
+            .take(room_count - 1)
+            .skip(1)
+            .filter(|(_, room)| room.vault.is_none())
+            .map(|(room_idx, room)| {
+                orientations
+                    .iter()
+                    .filter(move |o| vault_fits_in_room(o, room))
+                    .map(move |o| (room_idx, o.clone()))
+            })
+            .flatten()
+            .collect();
+        if candidates.len() > 0 {
+            let (room_idx, vault_stamp) = get_random_element(rng, &candidates).clone();
+            let room = &mut level.rooms[room_idx];
+            room.room_type = None;
+            room.features = vec![];
+            room.vault = Some(vault_stamp);
+            vault_count += 1;
+        }
+    }
+}
+
+pub fn stamp_vaults_onto_rooms(level: &mut Level) {
+    for room in level.rooms.iter_mut() {
+        if let Some(vault_stamp) = &room.vault {
+            let x = (room.stamp.width() - vault_stamp.width()) / 2;
+            let y = (room.stamp.height() - vault_stamp.height()) / 2;
+            room.stamp.stamp(vault_stamp, x, y);
+        }
+    }
+}
@@ -0,0 +1,194 @@
+// Gamepads are polled once a frame. A button reports an input on the frame it's pressed, the left
+// stick reports a direction when it's first pushed and then repeats while it's held, much like a
+// held key does. Both backends report buttons in the order of the standard gamepad mapping.
+#[cfg(not(target_arch = "wasm32"))]
+mod not_wasm;
+#[cfg(target_arch = "wasm32")]
+mod wasm;
+#[cfg(not(target_arch = "wasm32"))]
+use not_wasm::read_gamepad;
+#[cfg(target_arch = "wasm32")]
+use wasm::read_gamepad;
+
+use crate::control::Input;
+use serde::{Deserialize, Serialize};
+use std::cell::RefCell;
+
+const STICK_DEAD_ZONE: f32 = 0.5;
+const STICK_REPEAT_DELAY_MS: f32 = 350.0;
+const STICK_REPEAT_INTERVAL_MS: f32 = 150.0;
+
+#[derive(Hash, Eq, PartialEq, Serialize, Deserialize, Copy, Clone, Debug)]
+pub enum GamepadButton {
+    South,
+    East,
+    West,
+    North,
+    LeftBumper,
+    RightBumper,
+    LeftTrigger,
+    RightTrigger,
+    Select,
+    Start,
+    LeftThumb,
+    RightThumb,
+    DPadUp,
+    DPadDown,
+    DPadLeft,
+    DPadRight,
+}
+
+impl GamepadButton {
+    pub fn buttons() -> Box<[Self]> {
+        Box::new([
+            Self::South,
+            Self::East,
+            Self::West,
+            Self::North,
+            Self::LeftBumper,
+            Self::RightBumper,
+            Self::LeftTrigger,
+            Self::RightTrigger,
+            Self::Select,
+            Self::Start,
+            Self::LeftThumb,
+            Self::RightThumb,
+            Self::DPadUp,
+            Self::DPadDown,
+            Self::DPadLeft,
+            Self::DPadRight,
+        ])
+    }
+
+    pub fn get_name(&self) -> &'static str {
+        match self {
+            Self::South => "Pad South",
+            Self::East => "Pad East",
+            Self::West => "Pad West",
+            Self::North => "Pad North",
+            Self::LeftBumper => "Left Bumper",
+            Self::RightBumper => "Right Bumper",
+            Self::LeftTrigger => "Left Trigger",
+            Self::RightTrigger => "Right Trigger",
+            Self::Select => "Pad Select",
+            Self::Start => "Pad Start",
+            Self::LeftThumb => "Left Stick Press",
+            Self::RightThumb => "Right Stick Press",
+            Self::DPadUp => "D-Pad Up",
+            Self::DPadDown => "D-Pad Down",
+            Self::DPadLeft => "D-Pad Left",
+            Self::DPadRight => "D-Pad Right",
+        }
+    }
+}
+
+#[derive(Hash, Eq, PartialEq, Serialize, Deserialize, Copy, Clone, Debug)]
+pub enum StickDirection {
+    Up,
+    Down,
+    Left,
+    Right,
+    UpLeft,
+    UpRight,
+    DownLeft,
+    DownRight,
+}
+
+impl StickDirection {
+    pub fn get_name(&self) -> &'static str {
+        match self {
+            Self::Up => "Left Stick Up",
+            Self::Down => "Left Stick Down",
+            Self::Left => "Left Stick Left",
+            Self::Right => "Left Stick Right",
+            Self::UpLeft => "Left Stick Up Left",
+            Self::UpRight => "Left Stick Up Right",
+            Self::DownLeft => "Left Stick Down Left",
+            Self::DownRight => "Left Stick Down Right",
+        }
+    }
+
+    // y is positive upwards, the stick is split into eight equal slices around its centre
+    fn from_axes(x: f32, y: f32) -> Option<Self> {
+        if (x * x + y * y).sqrt() < STICK_DEAD_ZONE {
+            return None;
+        }
+        let slice = ((y.atan2(x).to_degrees() + 360.0 + 22.5) % 360.0 / 45.0) as u8;
+        Some(match slice {
+            0 => Self::Right,
+            1 => Self::UpRight,
+            2 => Self::Up,
+            3 => Self::UpLeft,
+            4 => Self::Left,
+            5 => Self::DownLeft,
+            6 => Self::Down,
+            _ => Self::DownRight,
+        })
+    }
+}
+
+#[derive(Default)]
+pub struct GamepadState {
+    pub buttons: Vec<bool>,
+    pub left_stick: (f32, f32),
+}
+
+fn get_stick_repeats(held_ms: f32) -> u32 {
+    match held_ms < STICK_REPEAT_DELAY_MS {
+        true => 0,
+        false => 1 + ((held_ms - STICK_REPEAT_DELAY_MS) / STICK_REPEAT_INTERVAL_MS) as u32,
+    }
+}
+
+#[derive(Default)]
+struct GamepadTracker {
+    previous_buttons: Vec<bool>,
+    stick: Option<StickDirection>,
+    stick_held_ms: f32,
+    pressed: Option<Input>,
+}
+
+impl GamepadTracker {
+    fn update(&mut self, state: GamepadState, elapsed_ms: f32) {
+        let button = GamepadButton::buttons()
+            .iter()
+            .enumerate()
+            .find(|(i, _)| {
+                state.buttons.get(*i) == Some(&true) && self.previous_buttons.get(*i) != Some(&true)
+            })
+            .map(|(_, button)| Input::GamepadButton(*button));
+        self.previous_buttons = state.buttons;
+        let direction = StickDirection::from_axes(state.left_stick.0, state.left_stick.1);
+        let stick = match direction {
+            Some(_) if direction != self.stick => {
+                self.stick_held_ms = 0.0;
+                direction
+            }
+            Some(_) => {
+                let repeats_before = get_stick_repeats(self.stick_held_ms);
+                self.stick_held_ms += elapsed_ms;
+                match get_stick_repeats(self.stick_held_ms) > repeats_before {
+                    true => direction,
+                    false => None,
+                }
+            }
+            None => None,
+        };
+        self.stick = direction;
+        self.pressed = button.or(stick.map(Input::LeftStick));
+    }
+}
+
+thread_local! {
+    static TRACKER: RefCell<GamepadTracker> = RefCell::new(GamepadTracker::default());
+}
+
+pub fn poll(elapsed_ms: f32) {
+    let state = read_gamepad();
+    TRACKER.with(|tracker| tracker.borrow_mut().update(state, elapsed_ms));
+}
+
+// the gamepad input pressed this frame, if any
+pub fn get_pressed() -> Option<Input> {
+    TRACKER.with(|tracker| tracker.borrow().pressed)
+}
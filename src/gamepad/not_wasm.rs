@@ -0,0 +1,50 @@
+use super::GamepadState;
+use gilrs::{Axis, Button, Gilrs};
+use std::cell::RefCell;
+
+// in the order of the standard gamepad mapping, gilrs calls the bumpers triggers
+const BUTTONS: [Button; 16] = [
+    Button::South,
+    Button::East,
+    Button::West,
+    Button::North,
+    Button::LeftTrigger,
+    Button::RightTrigger,
+    Button::LeftTrigger2,
+    Button::RightTrigger2,
+    Button::Select,
+    Button::Start,
+    Button::LeftThumb,
+    Button::RightThumb,
+    Button::DPadUp,
+    Button::DPadDown,
+    Button::DPadLeft,
+    Button::DPadRight,
+];
+
+thread_local! {
+    // without gamepad support on the platform there's simply never any input
+    static GILRS: RefCell<Option<Gilrs>> = RefCell::new(Gilrs::new().ok());
+}
+
+pub fn read_gamepad() -> GamepadState {
+    GILRS.with(|gilrs| {
+        let mut gilrs = gilrs.borrow_mut();
+        let gilrs = match gilrs.as_mut() {
+            Some(gilrs) => gilrs,
+            None => return GamepadState::default(),
+        };
+        // gilrs only updates the state of its gamepads as events are taken
+        while gilrs.next_event().is_some() {}
+        match gilrs.gamepads().next() {
+            Some((_id, gamepad)) => GamepadState {
+                buttons: BUTTONS.iter().map(|b| gamepad.is_pressed(*b)).collect(),
+                left_stick: (
+                    gamepad.value(Axis::LeftStickX),
+                    gamepad.value(Axis::LeftStickY),
+                ),
+            },
+            None => GamepadState::default(),
+        }
+    })
+}
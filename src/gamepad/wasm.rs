@@ -0,0 +1,19 @@
+use super::{GamepadButton, GamepadState};
+use wasm_bindgen::prelude::*;
+
+#[wasm_bindgen(module = "/js/gamepad.js")]
+extern "C" {
+    fn getGamepadButtons() -> u32;
+    fn getGamepadLeftStickX() -> f32;
+    fn getGamepadLeftStickY() -> f32;
+}
+
+pub fn read_gamepad() -> GamepadState {
+    let buttons = getGamepadButtons();
+    GamepadState {
+        buttons: (0..GamepadButton::buttons().len())
+            .map(|i| buttons & (1 << i) != 0)
+            .collect(),
+        left_stick: (getGamepadLeftStickX(), getGamepadLeftStickY()),
+    }
+}
@@ -14,6 +14,12 @@ pub const SHORT_TEXT_GO_UP: &str = "Go Up Stairs";
 pub const SHORT_TEXT_EXIT: &str = "Exit the dungeon";
 pub const SHORT_TEXT_MOVE: &str = "Move to location";
 pub const SHORT_TEXT_RELEASE: &str = "Release Object";
+pub const SHORT_TEXT_EAT: &str = "Eat";
+pub const SHORT_TEXT_COOK: &str = "Cook";
+pub const SHORT_TEXT_EXPLORE: &str = "Explore";
+pub const SHORT_TEXT_REST: &str = "Rest";
+pub const SHORT_TEXT_REPEAT: &str = "Repeat";
+pub const SHORT_TEXT_PLAY_MACRO: &str = "Play Macro";
 pub const DESCRIPTION_DISARM: &str = "Disarm Trap";
 pub const DESCRIPTION_ARM: &str = "Arm Trap";
 pub const DESCRIPTION_DOUSE: &str = "Douse";
@@ -30,3 +36,9 @@ pub const DESCRIPTION_GO_UP: &str = "Go Up Stairs";
 pub const DESCRIPTION_EXIT: &str = "Exit the dungeon";
 pub const DESCRIPTION_MOVE: &str = "Move to location";
 pub const DESCRIPTION_RELEASE: &str = "Release Object";
+pub const DESCRIPTION_EAT: &str = "Eat";
+pub const DESCRIPTION_COOK: &str = "Cook raw food";
+pub const DESCRIPTION_EXPLORE: &str = "Explore the level";
+pub const DESCRIPTION_REST: &str = "Rest until healed";
+pub const DESCRIPTION_REPEAT: &str = "Repeat the last action";
+pub const DESCRIPTION_PLAY_MACRO: &str = "Play a recorded macro";
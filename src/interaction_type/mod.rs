@@ -1,4 +1,5 @@
 mod copy;
+use crate::user_actions::MapAction;
 use specs::Entity;
 #[derive(Copy, Clone, PartialEq)]
 pub enum InteractionType {
@@ -19,6 +20,14 @@ pub enum InteractionType {
     OpenDoor(Entity),
     CloseDoor(Entity),
     OpenContainer(Entity),
+    Eat(Entity),
+    Cook(Entity),
+    Explore,
+    Rest,
+    // an action with the number of times left to do it
+    Repeat(MapAction, u32),
+    // a macro slot and the step to play next
+    PlayMacro(usize, usize),
 }
 
 impl InteractionType {
@@ -39,7 +48,13 @@ impl InteractionType {
             Self::GoUp(_) => copy::SHORT_TEXT_GO_UP,
             Self::Exit(_) => copy::SHORT_TEXT_EXIT,
             Self::Move(_) => copy::SHORT_TEXT_MOVE,
-            Self::Release => copy::SHORT_TEXT_RELEASE
+            Self::Release => copy::SHORT_TEXT_RELEASE,
+            Self::Eat(_) => copy::SHORT_TEXT_EAT,
+            Self::Cook(_) => copy::SHORT_TEXT_COOK,
+            Self::Explore => copy::SHORT_TEXT_EXPLORE,
+            Self::Rest => copy::SHORT_TEXT_REST,
+            Self::Repeat(_, _) => copy::SHORT_TEXT_REPEAT,
+            Self::PlayMacro(_, _) => copy::SHORT_TEXT_PLAY_MACRO,
         }
     }
     pub fn descriptive_text(&self) -> &str {
@@ -59,7 +74,13 @@ impl InteractionType {
             Self::GoUp(_) => copy::DESCRIPTION_GO_UP,
             Self::Exit(_) => copy::DESCRIPTION_EXIT,
             Self::Move(_) => copy::DESCRIPTION_MOVE,
-            Self::Release => copy::DESCRIPTION_RELEASE
+            Self::Release => copy::DESCRIPTION_RELEASE,
+            Self::Eat(_) => copy::DESCRIPTION_EAT,
+            Self::Cook(_) => copy::DESCRIPTION_COOK,
+            Self::Explore => copy::DESCRIPTION_EXPLORE,
+            Self::Rest => copy::DESCRIPTION_REST,
+            Self::Repeat(_, _) => copy::DESCRIPTION_REPEAT,
+            Self::PlayMacro(_, _) => copy::DESCRIPTION_PLAY_MACRO,
         }
     }
 }
@@ -1,27 +1,225 @@
-use crate::components::{Container, Inventory, Name};
-use specs::{Entity, World, WorldExt};
+use crate::components::{
+    AreaOfEffect, Armable, Bulk, Capacity, CausesDamage, CausesFire, Consumable, Container,
+    Cookable, Edible, Equipment, Identifiable, Identifies, Info, Inventory, Item, Name, Objective,
+    Paralyze, ProvidesHealing, Ranged, Renderable, Saveable, Stackable, Trap,
+};
+use crate::entity_set::EntitySet;
+use specs::{
+    saveload::{MarkedBuilder, SimpleMarker},
+    storage::GenericReadStorage,
+    Builder, Entity, Join, World, WorldExt, WriteStorage,
+};
 
 pub type InventoryList = Vec<(Entity, String)>;
 
+fn get_display_name<N, S>(item: Entity, names: &N, stackables: &S) -> String
+where
+    N: GenericReadStorage<Component = Name>,
+    S: GenericReadStorage<Component = Stackable>,
+{
+    let name = names.get(item).unwrap().name.clone();
+    match stackables.get(item) {
+        Some(stackable) if stackable.quantity > 1 => format!("{} (x{})", name, stackable.quantity),
+        _ => name,
+    }
+}
+
 pub fn get_player_inventory_list(ecs: &World) -> InventoryList {
     let player_entity = ecs.fetch::<Entity>();
     let inventories = ecs.read_storage::<Inventory>();
     let player_inventory = inventories.get(*player_entity).unwrap();
     let names = ecs.read_storage::<Name>();
+    let stackables = ecs.read_storage::<Stackable>();
     player_inventory
         .items
         .iter()
-        .map(|e| (*e, names.get(*e).unwrap().name.clone()))
+        .map(|e| (*e, get_display_name(*e, &names, &stackables)))
         .collect()
 }
 
 pub fn get_container_inventory_list(ecs: &World, container_entity: &Entity) -> InventoryList {
     let names = ecs.read_storage::<Name>();
+    let stackables = ecs.read_storage::<Stackable>();
     let containers = ecs.read_storage::<Container>();
     let container = containers.get(*container_entity).unwrap();
     container
         .items
         .iter()
-        .map(|e| (*e, names.get(*e).unwrap().name.clone()))
+        .map(|e| (*e, get_display_name(*e, &names, &stackables)))
         .collect()
 }
+
+// a stack weighs as much as everything in it, items without bulk weigh nothing
+pub fn get_bulk_of_item<B, S>(item: Entity, bulks: &B, stackables: &S) -> Bulk
+where
+    B: GenericReadStorage<Component = Bulk>,
+    S: GenericReadStorage<Component = Stackable>,
+{
+    let quantity = stackables
+        .get(item)
+        .map_or(1, |stackable| stackable.quantity);
+    match bulks.get(item) {
+        Some(bulk) => Bulk {
+            weight: bulk.weight * quantity,
+            volume: bulk.volume * quantity,
+        },
+        None => Bulk {
+            weight: 0,
+            volume: 0,
+        },
+    }
+}
+
+pub fn get_bulk_of_items<'a, B, S>(
+    items: impl Iterator<Item = &'a Entity>,
+    bulks: &B,
+    stackables: &S,
+) -> Bulk
+where
+    B: GenericReadStorage<Component = Bulk>,
+    S: GenericReadStorage<Component = Stackable>,
+{
+    items
+        .map(|item| get_bulk_of_item(*item, bulks, stackables))
+        .fold(
+            Bulk {
+                weight: 0,
+                volume: 0,
+            },
+            |total, bulk| Bulk {
+                weight: total.weight + bulk.weight,
+                volume: total.volume + bulk.volume,
+            },
+        )
+}
+
+// another stack of the same item that item could be merged into
+pub fn find_matching_stack<'a, N, S>(
+    items: impl Iterator<Item = &'a Entity>,
+    item: Entity,
+    names: &N,
+    stackables: &S,
+) -> Option<Entity>
+where
+    N: GenericReadStorage<Component = Name>,
+    S: GenericReadStorage<Component = Stackable>,
+{
+    stackables.get(item)?;
+    let name = &names.get(item)?.name;
+    items
+        .filter(|other| **other != item)
+        .find(|other| {
+            stackables.get(**other).is_some()
+                && names
+                    .get(**other)
+                    .map_or(false, |other| &other.name == name)
+        })
+        .copied()
+}
+
+// uses up one item from a stack, true when there are more left so the item should be kept
+pub fn take_one_from_stack(stackables: &mut WriteStorage<Stackable>, item: Entity) -> bool {
+    match stackables.get_mut(item) {
+        Some(stackable) if stackable.quantity > 1 => {
+            stackable.quantity -= 1;
+            true
+        }
+        _ => false,
+    }
+}
+
+macro_rules! copy_components {
+    ($world:expr, $from:expr, $to:expr, $($type:ty),*) => {
+        $(
+            let component = $world.read_storage::<$type>().get($from).cloned();
+            if let Some(component) = component {
+                $world
+                    .write_storage::<$type>()
+                    .insert($to, component)
+                    .expect("could not copy component to split stack");
+            }
+        )*
+    };
+}
+
+// takes a single item off a stack as its own entity, the rest of the stack stays put
+pub fn split_stack(world: &mut World, stack: Entity) -> Entity {
+    let is_stacked = world
+        .read_storage::<Stackable>()
+        .get(stack)
+        .map_or(false, |stackable| stackable.quantity > 1);
+    if !is_stacked {
+        return stack;
+    }
+    let item = world
+        .create_entity()
+        .marked::<SimpleMarker<Saveable>>()
+        .build();
+    copy_components!(
+        world,
+        stack,
+        item,
+        Name,
+        Renderable,
+        Info,
+        Item,
+        Bulk,
+        Consumable,
+        ProvidesHealing,
+        Ranged,
+        CausesDamage,
+        AreaOfEffect,
+        CausesFire,
+        Paralyze,
+        Trap,
+        Armable,
+        Edible,
+        Cookable,
+        Identifiable,
+        Identifies
+    );
+    let mut stackables = world.write_storage::<Stackable>();
+    take_one_from_stack(&mut stackables, stack);
+    stackables
+        .insert(item, Stackable { quantity: 1 })
+        .expect("could not insert stackable for split stack");
+    item
+}
+
+pub fn get_player_load_text(world: &World) -> String {
+    let player_entity = world.fetch::<Entity>();
+    let inventories = world.read_storage::<Inventory>();
+    let player_inventory = inventories.get(*player_entity).unwrap();
+    let bulks = world.read_storage::<Bulk>();
+    let stackables = world.read_storage::<Stackable>();
+    let equipped = world
+        .read_storage::<Equipment>()
+        .get(*player_entity)
+        .map_or(EntitySet::new(), |equipment| equipment.as_items());
+    let held = get_bulk_of_items(
+        player_inventory.items.iter().chain(equipped.iter()),
+        &bulks,
+        &stackables,
+    );
+    match world.read_storage::<Capacity>().get(*player_entity) {
+        Some(capacity) => format!(
+            "Weight {}/{} lbs, Space {}/{}",
+            held.weight, capacity.max_weight, held.volume, capacity.max_volume
+        ),
+        None => format!("Weight {} lbs, Space {}", held.weight, held.volume),
+    }
+}
+
+pub fn has_objective_in_backpack(world: &World) -> bool {
+    let player_ent = world.fetch::<Entity>();
+    let inventories = world.read_storage::<Inventory>();
+    let player_inventory = inventories.get(*player_ent).unwrap();
+    let entities = world.entities();
+    let objectives = world.read_storage::<Objective>();
+    for (entity, _objective) in (&entities, &objectives).join() {
+        if player_inventory.items.contains(&entity) {
+            return true;
+        }
+    }
+    false
+}
@@ -5,6 +5,8 @@ extern crate specs_derive;
 extern crate serde;
 mod ai;
 mod artwork;
+mod audio;
+mod auto_explore;
 mod components;
 mod control;
 mod copy;
@@ -13,14 +15,18 @@ mod debug;
 mod dungeon;
 mod entity_option;
 mod entity_set;
+mod gamepad;
 mod interaction_type;
 mod inventory;
 mod menu;
-mod music;
+mod options;
 mod patches;
+#[cfg(not(target_arch = "wasm32"))]
+mod paths;
 mod persistence;
 mod player;
 mod ranged;
+mod rest;
 mod run_state;
 mod screens;
 mod services;
@@ -28,6 +34,7 @@ mod settings;
 mod spawner;
 mod state;
 mod systems;
+mod travel;
 mod types;
 mod ui_components;
 mod user_actions;
@@ -35,26 +42,45 @@ mod utils;
 mod world_utils;
 // mod states;
 
-use music::Music;
+use audio::MusicDirector;
 use run_state::RunState;
-use screens::{SCREEN_HEIGHT, SCREEN_WIDTH};
+use screens::{sprite_sheet::SPRITE_SHEET_FONT, SCREEN_HEIGHT, SCREEN_WIDTH};
 use settings::Settings;
 use state::State;
 
+rltk::embedded_resource!(SPRITE_SHEET, "../resources/tiles.png");
+
 #[wasm_bindgen]
 pub fn start() {
+    rltk::link_resource!(SPRITE_SHEET, "resources/tiles.png");
+    let settings = Settings::load();
+    let mut world = world_utils::get_world();
+    let mut audio = audio::get_audio_backend();
+    state::apply_preferences(&settings.preferences, &mut world, audio.as_mut());
+    let fullscreen = settings.preferences.fullscreen;
+    let text_font = settings.preferences.text_font.get_file();
+    let (tile_width, tile_height) = settings.preferences.text_font.get_tile_dimensions();
     let gs = State {
-        world: world_utils::get_world(),
+        world,
         run_state: RunState::MainMenu { highlighted: 0 },
         queued_action: None,
-        settings: Settings::load(),
-        music: Music::new(),
+        last_action: None,
+        recording_macro: None,
+        settings,
+        audio,
+        music_director: MusicDirector::new(),
+        monsters_in_view: false,
     };
-    let context = RltkBuilder::simple(SCREEN_WIDTH, SCREEN_HEIGHT)
-        .unwrap()
+    let context = RltkBuilder::new()
+        .with_dimensions(SCREEN_WIDTH, SCREEN_HEIGHT)
+        .with_tile_dimensions(tile_width, tile_height)
+        .with_font(text_font, tile_width, tile_height)
+        .with_font(SPRITE_SHEET_FONT, 16, 16)
+        .with_simple_console(SCREEN_WIDTH, SCREEN_HEIGHT, SPRITE_SHEET_FONT)
+        .with_sparse_console(SCREEN_WIDTH, SCREEN_HEIGHT, text_font)
         .with_title("Apprentice")
         .with_advanced_input(true)
-        .with_fullscreen(false)
+        .with_fullscreen(fullscreen)
         .build()
         .expect("failed to create context");
     rltk::main_loop(context, gs).expect("failed to start apprentice");
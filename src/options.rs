@@ -0,0 +1,177 @@
+use crate::settings::Preferences;
+
+const VOLUME_STEP: u8 = 10;
+const VIEWPORT_MARGIN_STEP: i32 = 4;
+const MAX_VIEWPORT_MARGIN: i32 = 20;
+
+#[derive(PartialEq, Clone, Copy, Debug)]
+pub enum OptionsTab {
+    Display,
+    Audio,
+    Gameplay,
+    Controls,
+}
+
+impl OptionsTab {
+    pub fn tabs() -> Box<[Self]> {
+        Box::new([Self::Display, Self::Audio, Self::Gameplay, Self::Controls])
+    }
+
+    pub fn get_name(&self) -> &'static str {
+        match self {
+            Self::Display => "Display",
+            Self::Audio => "Audio",
+            Self::Gameplay => "Gameplay",
+            Self::Controls => "Controls",
+        }
+    }
+
+    pub fn get_next(&self) -> Self {
+        let tabs = Self::tabs();
+        let idx = tabs.iter().position(|t| t == self).unwrap();
+        tabs[(idx + 1) % tabs.len()]
+    }
+
+    pub fn get_previous(&self) -> Self {
+        let tabs = Self::tabs();
+        let idx = tabs.iter().position(|t| t == self).unwrap();
+        tabs[(idx + tabs.len() - 1) % tabs.len()]
+    }
+
+    // the controls tab lists map actions instead
+    pub fn get_options(&self) -> Box<[PreferenceOption]> {
+        match self {
+            Self::Display => Box::new([
+                PreferenceOption::RenderMode,
+                PreferenceOption::TextFont,
+                PreferenceOption::Fullscreen,
+                PreferenceOption::Scanlines,
+                PreferenceOption::ShowMinimap,
+                PreferenceOption::ViewportMargin,
+            ]),
+            Self::Audio => Box::new([
+                PreferenceOption::MusicVolume,
+                PreferenceOption::MusicMuted,
+                PreferenceOption::SoundEffectVolume,
+                PreferenceOption::SoundEffectsMuted,
+            ]),
+            Self::Gameplay => Box::new([
+                PreferenceOption::MessageVerbosity,
+                PreferenceOption::ExploreStopOnMonster,
+                PreferenceOption::ExploreStopOnItem,
+            ]),
+            Self::Controls => Box::new([]),
+        }
+    }
+}
+
+#[derive(PartialEq, Clone, Copy, Debug)]
+pub enum PreferenceOption {
+    RenderMode,
+    TextFont,
+    Fullscreen,
+    Scanlines,
+    ShowMinimap,
+    ViewportMargin,
+    MusicVolume,
+    MusicMuted,
+    SoundEffectVolume,
+    SoundEffectsMuted,
+    MessageVerbosity,
+    ExploreStopOnMonster,
+    ExploreStopOnItem,
+}
+
+fn get_next_volume(volume: u8) -> u8 {
+    match volume >= 100 {
+        true => 0,
+        false => (volume + VOLUME_STEP).min(100),
+    }
+}
+
+fn get_next_viewport_margin(margin: i32) -> i32 {
+    match margin >= MAX_VIEWPORT_MARGIN {
+        true => 0,
+        false => (margin + VIEWPORT_MARGIN_STEP).min(MAX_VIEWPORT_MARGIN),
+    }
+}
+
+fn get_toggle_text(value: bool) -> String {
+    match value {
+        true => String::from("On"),
+        false => String::from("Off"),
+    }
+}
+
+impl PreferenceOption {
+    pub fn get_label(&self) -> &'static str {
+        match self {
+            Self::RenderMode => "Display Mode",
+            Self::TextFont => "Font (on restart)",
+            Self::Fullscreen => "Fullscreen (on restart)",
+            Self::Scanlines => "Scanlines",
+            Self::ShowMinimap => "Minimap",
+            Self::ViewportMargin => "Scroll Margin",
+            Self::MusicVolume => "Music Volume",
+            Self::MusicMuted => "Mute Music",
+            Self::SoundEffectVolume => "Sound Effects Volume",
+            Self::SoundEffectsMuted => "Mute Sound Effects",
+            Self::MessageVerbosity => "Messages",
+            Self::ExploreStopOnMonster => "Stop Exploring For Monsters",
+            Self::ExploreStopOnItem => "Stop Exploring For Items",
+        }
+    }
+
+    pub fn get_value(&self, preferences: &Preferences) -> String {
+        match self {
+            Self::RenderMode => preferences.render_mode.get_name().to_string(),
+            Self::TextFont => preferences.text_font.get_name().to_string(),
+            Self::Fullscreen => get_toggle_text(preferences.fullscreen),
+            Self::Scanlines => get_toggle_text(preferences.scanlines),
+            Self::ShowMinimap => get_toggle_text(preferences.show_minimap),
+            Self::ViewportMargin => format!("{} tiles", preferences.viewport_margin),
+            Self::MusicVolume => format!("{}%", preferences.music_volume),
+            Self::MusicMuted => get_toggle_text(preferences.music_muted),
+            Self::SoundEffectVolume => format!("{}%", preferences.sound_effect_volume),
+            Self::SoundEffectsMuted => get_toggle_text(preferences.sound_effects_muted),
+            Self::MessageVerbosity => preferences.message_verbosity.get_name().to_string(),
+            Self::ExploreStopOnMonster => {
+                get_toggle_text(preferences.explore_interrupts.on_monster)
+            }
+            Self::ExploreStopOnItem => get_toggle_text(preferences.explore_interrupts.on_item),
+        }
+    }
+
+    pub fn change(&self, preferences: &mut Preferences) {
+        match self {
+            Self::RenderMode => preferences.render_mode = preferences.render_mode.get_next(),
+            Self::TextFont => preferences.text_font = preferences.text_font.get_next(),
+            Self::Fullscreen => preferences.fullscreen = !preferences.fullscreen,
+            Self::Scanlines => preferences.scanlines = !preferences.scanlines,
+            Self::ShowMinimap => preferences.show_minimap = !preferences.show_minimap,
+            Self::ViewportMargin => {
+                preferences.viewport_margin = get_next_viewport_margin(preferences.viewport_margin)
+            }
+            Self::MusicVolume => {
+                preferences.music_volume = get_next_volume(preferences.music_volume)
+            }
+            Self::MusicMuted => preferences.music_muted = !preferences.music_muted,
+            Self::SoundEffectVolume => {
+                preferences.sound_effect_volume = get_next_volume(preferences.sound_effect_volume)
+            }
+            Self::SoundEffectsMuted => {
+                preferences.sound_effects_muted = !preferences.sound_effects_muted
+            }
+            Self::MessageVerbosity => {
+                preferences.message_verbosity = preferences.message_verbosity.get_next()
+            }
+            Self::ExploreStopOnMonster => {
+                preferences.explore_interrupts.on_monster =
+                    !preferences.explore_interrupts.on_monster
+            }
+            Self::ExploreStopOnItem => {
+                preferences.explore_interrupts.on_item = !preferences.explore_interrupts.on_item
+            }
+        }
+    }
+}
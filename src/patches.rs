@@ -1,4 +1,13 @@
 use rltk::{BEvent, Rltk, VirtualKeyCode, INPUT};
+use std::sync::atomic::{AtomicBool, Ordering};
+
+// rltk only reports left clicks on the context, so right clicks are picked out
+// of the event queue each frame
+static RIGHT_CLICKED: AtomicBool = AtomicBool::new(false);
+
+pub fn right_clicked() -> bool {
+    RIGHT_CLICKED.load(Ordering::Relaxed)
+}
 
 pub fn patch_mod_keys(ctx: &mut Rltk) {
     let mut input = INPUT.lock();
@@ -9,9 +18,17 @@ pub fn patch_mod_keys(ctx: &mut Rltk) {
     ctx.control = input.key_pressed_set().contains(&VirtualKeyCode::LControl)
         || input.key_pressed_set().contains(&VirtualKeyCode::RControl);
 
-    #[allow(clippy::single_match)]
+    ctx.alt = input.key_pressed_set().contains(&VirtualKeyCode::LAlt)
+        || input.key_pressed_set().contains(&VirtualKeyCode::RAlt);
+
+    RIGHT_CLICKED.store(false, Ordering::Relaxed);
+
     input.for_each_message(|event| match event {
         BEvent::CloseRequested => ctx.quitting = true,
+        BEvent::MouseClick {
+            button: 1,
+            pressed: true,
+        } => RIGHT_CLICKED.store(true, Ordering::Relaxed),
         _ => (),
     });
 }
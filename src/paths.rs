@@ -0,0 +1,113 @@
+// Where settings and saves live on desktop builds, the web build uses localStorage instead.
+// The directory can be overridden with `--data-dir <path>` or the APPRENTICE_DATA_DIR
+// environment variable, otherwise settings go in the usual per-OS config directory and saves in the
+// per-OS data directory, which are the same place on Windows and macOS.
+use std::{env, fs::DirBuilder, io, path::PathBuf};
+
+const DATA_DIR_FLAG: &str = "--data-dir";
+const DATA_DIR_ENV_VAR: &str = "APPRENTICE_DATA_DIR";
+
+fn get_env_dir(name: &str) -> Option<PathBuf> {
+    env::var_os(name)
+        .filter(|value| !value.is_empty())
+        .map(PathBuf::from)
+}
+
+fn get_flag_dir() -> Option<PathBuf> {
+    let mut args = env::args().skip(1);
+    while let Some(arg) = args.next() {
+        if arg == DATA_DIR_FLAG {
+            return args.next().map(PathBuf::from);
+        }
+        if let Some(path) = arg.strip_prefix(&format!("{}=", DATA_DIR_FLAG)) {
+            return Some(PathBuf::from(path));
+        }
+    }
+    None
+}
+
+#[cfg(target_os = "windows")]
+fn get_platform_config_dir() -> Option<PathBuf> {
+    get_env_dir("APPDATA").map(|dir| dir.join("Apprentice"))
+}
+
+#[cfg(target_os = "macos")]
+fn get_platform_config_dir() -> Option<PathBuf> {
+    get_env_dir("HOME").map(|dir| dir.join("Library/Application Support/apprentice"))
+}
+
+#[cfg(not(any(target_os = "windows", target_os = "macos")))]
+fn get_platform_config_dir() -> Option<PathBuf> {
+    get_env_dir("XDG_CONFIG_HOME")
+        .or_else(|| get_env_dir("HOME").map(|dir| dir.join(".config")))
+        .map(|dir| dir.join("apprentice"))
+}
+
+#[cfg(any(target_os = "windows", target_os = "macos"))]
+fn get_platform_data_dir() -> Option<PathBuf> {
+    get_platform_config_dir()
+}
+
+#[cfg(not(any(target_os = "windows", target_os = "macos")))]
+fn get_platform_data_dir() -> Option<PathBuf> {
+    get_env_dir("XDG_DATA_HOME")
+        .or_else(|| get_env_dir("HOME").map(|dir| dir.join(".local/share")))
+        .map(|dir| dir.join("apprentice"))
+}
+
+fn get_dir(platform_dir: fn() -> Option<PathBuf>) -> PathBuf {
+    get_flag_dir()
+        .or_else(|| get_env_dir(DATA_DIR_ENV_VAR))
+        .or_else(platform_dir)
+        // with nowhere better to go fall back to the working directory
+        .unwrap_or_else(|| PathBuf::from("."))
+}
+
+// Older builds kept settings in the macOS style directory on every platform and the save in the
+// working directory, later ones saved to the config directory.
+fn get_legacy_file_paths(file_name: &str) -> Vec<PathBuf> {
+    get_env_dir("HOME")
+        .map(|dir| dir.join("Library/Application Support/apprentice"))
+        .into_iter()
+        .chain(get_platform_config_dir())
+        .chain(Some(PathBuf::from(".")))
+        .map(|dir| dir.join(file_name))
+        .collect()
+}
+
+// Settings, like key bindings and preferences.
+pub fn get_config_dir() -> PathBuf {
+    get_dir(get_platform_config_dir)
+}
+
+pub fn get_config_file_path(file_name: &str) -> PathBuf {
+    get_config_dir().join(file_name)
+}
+
+pub fn create_config_dir() -> io::Result<()> {
+    DirBuilder::new().recursive(true).create(get_config_dir())
+}
+
+// Saved games.
+pub fn get_data_dir() -> PathBuf {
+    get_dir(get_platform_data_dir)
+}
+
+pub fn get_data_file_path(file_name: &str) -> PathBuf {
+    get_data_dir().join(file_name)
+}
+
+pub fn create_data_dir() -> io::Result<()> {
+    DirBuilder::new().recursive(true).create(get_data_dir())
+}
+
+// Files are always written to `path`, but when nothing is there yet they're read from wherever an
+// older build left them.
+pub fn find_existing_file(path: PathBuf, file_name: &str) -> Option<PathBuf> {
+    if path.exists() {
+        return Some(path);
+    }
+    get_legacy_file_paths(file_name)
+        .into_iter()
+        .find(|legacy_path| legacy_path.exists())
+}
@@ -5,13 +5,17 @@
 // to replace the custom macros
 use crate::components::{
     AreaOfEffect, Armable, BlocksTile, Blood, CausesDamage, CausesFire, CausesLight, CombatStats,
+    Cookable, CookingSpot, Edible, Hunger, Rotting, Bulk, Capacity, Stackable,
+    Identifiable, Identifies,
     Paralyze, Consumable, Container, DamageHistory, Disarmable, Door, Dousable, EntityMoved,
     EntryTrigger, Equipable, Equipment, Flammable, Furniture, Grabbable, Grabbing, Hidden, Hiding,
     HidingSpot, Info, Inventory, Item, Lightable, Memory, Monster, Name, Objective, OnFire,
-    ParticleLifetime, Player, Position, ProvidesHealing, Ranged, Renderable, Saveable,
-    SerializationHelper, SingleActivation, SufferDamage, Trap, Triggered, Viewshed,
+    ParticleLifetime, Player, Position, ProvidesHealing, Ranged, Regeneration, Renderable,
+    Saveable, SerializationHelper, SingleActivation, SufferDamage, Trap, Triggered, Viewshed,
 };
 use crate::dungeon::{constants::MAP_COUNT, dungeon::Dungeon};
+use crate::screens::viewport::Viewport;
+use crate::services::Identification;
 use specs::{
     error::NoError,
     join::Join,
@@ -53,12 +57,33 @@ macro_rules! deserialize_individually {
   };
 }
 
+// Components are saved one after another, so anything added since a save was written is missing
+// from the end of it. Those are only read when there's something left to read.
+macro_rules! deserialize_individually_if_present {
+  ($world:expr, $de:expr, $data:expr, $( $type:ty),*) => {
+      $(
+      if $de.end().is_err() {
+          DeserializeComponents::<NoError, _>::deserialize(
+              &mut ( &mut $world.write_storage::<$type>(), ),
+              &mut $data.0,
+              &mut $data.1,
+              &mut $data.2,
+              &mut $de,
+          )
+          .unwrap();
+      }
+      )*
+  };
+}
+
 fn create_save_game_helpers(world: &mut World) {
     let dungeon_copy = world.get_mut::<Dungeon>().unwrap().clone();
+    let identification_copy = world.get_mut::<Identification>().unwrap().clone();
     world
         .create_entity()
         .with(SerializationHelper {
             dungeon: dungeon_copy,
+            identification: identification_copy,
         })
         .marked::<SimpleMarker<Saveable>>()
         .build();
@@ -134,7 +159,18 @@ pub fn save_game_with_writer<T: Write>(world: &mut World, writer: T) -> serde_js
             DamageHistory,
             Inventory,
             Door,
-            SerializationHelper
+            SerializationHelper,
+            Regeneration,
+            Hunger,
+            Edible,
+            Cookable,
+            Rotting,
+            CookingSpot,
+            Bulk,
+            Capacity,
+            Stackable,
+            Identifiable,
+            Identifies
         );
     }
     delete_helpers(world);
@@ -201,6 +237,22 @@ fn deserialize_from_string(world: &mut World, game_string: String) {
         Door,
         SerializationHelper
     );
+    deserialize_individually_if_present!(
+        world,
+        deserializer,
+        ent_markers,
+        Regeneration,
+        Hunger,
+        Edible,
+        Cookable,
+        Rotting,
+        CookingSpot,
+        Bulk,
+        Capacity,
+        Stackable,
+        Identifiable,
+        Identifies
+    );
 }
 
 fn get_dungeon(world: &mut World) -> Dungeon {
@@ -223,6 +275,18 @@ fn populate_map_from_helper(world: &mut World) {
     world.insert(dungeon);
 }
 
+fn populate_identification_from_helper(world: &mut World) {
+    let identification = {
+        let serialization_helpers = world.read_storage::<SerializationHelper>();
+        (serialization_helpers)
+            .join()
+            .map(|h| h.identification.clone())
+            .next()
+            .unwrap_or_else(Identification::new)
+    };
+    world.insert(identification);
+}
+
 fn get_player_parts(world: &mut World) -> Entity {
     let entities = world.entities();
     let player = world.read_storage::<Player>();
@@ -240,6 +304,8 @@ pub fn load_game_from_string(world: &mut World, game_string: String) {
     world.delete_all();
     deserialize_from_string(world, game_string);
     populate_map_from_helper(world);
+    populate_identification_from_helper(world);
     delete_helpers(world);
     populate_player(world);
+    world.fetch_mut::<Viewport>().reset();
 }
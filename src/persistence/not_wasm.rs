@@ -1,26 +1,38 @@
 use std::fs::{read_to_string, remove_file, File};
-use std::path::Path;
+use std::path::PathBuf;
 use specs::World;
 use super::common::{load_game_from_string, save_game_with_writer};
+use crate::paths;
 
-const SAVE_FILE_PATH: &str = "./tell-lands-save.json";
+const SAVE_FILE_NAME: &str = "tell-lands-save.json";
+
+fn get_save_file_path() -> PathBuf {
+    paths::get_data_file_path(SAVE_FILE_NAME)
+}
+
+// the save that would be loaded, which may still be where an older build wrote it
+fn find_save_file() -> Option<PathBuf> {
+    paths::find_existing_file(get_save_file_path(), SAVE_FILE_NAME)
+}
 
 pub fn load_game(world: &mut World) {
-    let game_string = read_to_string(SAVE_FILE_PATH).unwrap();
+    let game_string = read_to_string(find_save_file().unwrap()).unwrap();
     load_game_from_string(world, game_string);
 }
 
 pub fn has_save_game() -> bool {
-    Path::new(SAVE_FILE_PATH).exists()
+    find_save_file().is_some()
 }
 
 pub fn delete_save() {
-    if has_save_game() {
-        remove_file(SAVE_FILE_PATH).expect("unable to delete save file")
+    // a legacy save left behind would be picked up again the next time the game starts
+    while let Some(path) = find_save_file() {
+        remove_file(path).expect("unable to delete save file")
     }
 }
 
 pub fn save_game(world: &mut World) {
-    let writer = File::create(SAVE_FILE_PATH).unwrap();
+    paths::create_data_dir().expect("unable to create save directory");
+    let writer = File::create(get_save_file_path()).unwrap();
     save_game_with_writer(world, writer);
 }
\ No newline at end of file
@@ -1,13 +1,15 @@
 use crate::components::{
     door::DoorState, equipable::EquipmentPositions, BlocksTile, Container, Disarmable, Door,
-    Grabbing, Hidden, HidingSpot, Item, Monster, Position, Trap, WantsToCloseDoor,
-    WantsToDisarmTrap, WantsToDouse, WantsToEquip, WantsToGoDownStairs, WantsToGoUpStairs,
-    WantsToGrab, WantsToHide, WantsToLight, WantsToMelee, WantsToMove, WantsToOpenDoor,
-    WantsToPickUpItem, WantsToReleaseGrabbed, WantsToSearchHidden, WantsToTrap, WantsToUse,
+    Edible, Grabbing, Hidden, HidingSpot, Item, Monster, Position, Trap, WantsToCloseDoor,
+    WantsToCook, WantsToDisarmTrap, WantsToDouse, WantsToDropItem, WantsToEat, WantsToEquip,
+    WantsToGoDownStairs, WantsToGoUpStairs, WantsToGrab, WantsToHide, WantsToLight, WantsToMelee,
+    WantsToMove, WantsToOpenDoor, WantsToPickUpItem, WantsToReleaseGrabbed, WantsToSearchHidden,
+    WantsToTrap, WantsToUse,
 };
 use crate::dungeon::{dungeon::Dungeon, level_utils};
 use crate::entity_option::EntityOption;
 use crate::interaction_type::InteractionType;
+use crate::inventory;
 use specs::{Component, Entity, World, WorldExt};
 use std::collections::HashSet;
 
@@ -33,11 +35,16 @@ pub fn use_item(world: &mut World, item: Entity, target: Option<usize>) {
         let traps = world.read_storage::<Trap>();
         traps.get(item).is_some()
     };
+    let is_food = {
+        let edibles = world.read_storage::<Edible>();
+        edibles.get(item).is_some()
+    };
     match is_trap {
         true => {
             insert_intent(world, WantsToTrap { item, target })
                 .expect("Unable To Insert Trap Intent");
         }
+        false if is_food => eat_food(world, item),
         false => {
             insert_intent(world, WantsToUse { item, target })
                 .expect("Unable To Insert Use Item Intent");
@@ -55,6 +62,23 @@ pub fn close_door(world: &mut World, door: Entity) {
         .expect("could not insert wants to close door for player");
 }
 
+// drops one item off the top of a stack unless the whole stack is wanted
+pub fn drop_item(world: &mut World, item: Entity, whole_stack: bool) {
+    let item = match whole_stack {
+        true => item,
+        false => inventory::split_stack(world, item),
+    };
+    insert_intent(world, WantsToDropItem { item }).expect("Unable To Insert Drop Item Intent");
+}
+
+pub fn eat_food(world: &mut World, food: Entity) {
+    insert_intent(world, WantsToEat { food }).expect("could not insert wants to eat for player");
+}
+
+pub fn cook_food(world: &mut World, stove: Entity) {
+    insert_intent(world, WantsToCook { stove }).expect("could not insert wants to cook for player");
+}
+
 pub fn search_hidden(world: &mut World) {
     insert_intent(world, WantsToSearchHidden {})
         .expect("could not insert wants to search hidden for player");
@@ -161,6 +185,8 @@ pub fn interact(world: &mut World, interaction_type: InteractionType) {
         InteractionType::GoUp(idx) => go_up_stairs(world, idx),
         InteractionType::Move(idx) => move_to_position(world, idx),
         InteractionType::Release => release_entity(world),
+        InteractionType::Eat(ent) => eat_food(world, ent),
+        InteractionType::Cook(ent) => cook_food(world, ent),
         _ => {}
     }
 }
@@ -252,7 +278,8 @@ pub fn get_default_action(world: &World, delta_x: i32, delta_y: i32) -> Interact
         Some(i) => i == destination_index,
         None => false,
     };
-    if idx_is_stairs_down {
+    let idx_is_branch_stairs = level.get_branch_at_idx(destination_index).is_some();
+    if idx_is_stairs_down || idx_is_branch_stairs {
         return InteractionType::GoDown(destination_index);
     }
     let idx_is_stairs_up = match stairs_up_idx {
@@ -2,7 +2,7 @@ use std::collections::HashSet;
 
 use crate::components::{Position, Viewshed};
 use crate::dungeon::{dungeon::Dungeon, level_utils};
-use crate::screens::utils::get_render_offset_for_xy;
+use crate::screens::utils::{get_player_view_center, get_render_offset_for_xy};
 use rltk::Rltk;
 use specs::{Entity, World, WorldExt};
 
@@ -28,13 +28,20 @@ pub fn get_visible_tiles_in_range(world: &World, range: u32) -> HashSet<usize> {
         .collect()
 }
 
+pub fn get_target_at_cursor(tiles: &HashSet<usize>, cursor: usize) -> Option<usize> {
+    match tiles.contains(&cursor) {
+        true => Some(cursor),
+        false => None,
+    }
+}
+
 pub fn get_target<'a>(world: &World, ctx: &mut Rltk, tiles: &'a HashSet<usize>) -> Option<usize> {
     let player_ent = world.fetch::<Entity>();
     let dungeon = world.fetch::<Dungeon>();
     let positions = world.read_storage::<Position>();
     let player_position = positions.get(*player_ent).unwrap();
     let level_width = dungeon.get_level(player_position.level).unwrap().width;
-    let (center_x, center_y) = level_utils::idx_xy(level_width as u32, player_position.idx);
+    let (center_x, center_y) = get_player_view_center(world);
     let (mouse_x, mouse_y) = ctx.mouse_pos();
     let (offset_x, offset_y) = get_render_offset_for_xy(center_x, center_y, mouse_x, mouse_y);
     let idx = level_utils::xy_idx(level_width as u32, offset_x, offset_y);
@@ -0,0 +1,56 @@
+use crate::components::{hunger::HungerState, CombatStats, Hunger, OnFire, Position};
+use crate::dungeon::{dungeon::Dungeon, level_utils};
+use specs::{Entity, World, WorldExt};
+use std::iter;
+
+pub enum RestStep {
+    Rest,
+    Healed,
+    Burning,
+    Hungry,
+}
+
+// fire hurts everything next to it, so standing beside a fire counts as burning
+fn is_burning(world: &World) -> bool {
+    let player_ent = world.fetch::<Entity>();
+    let on_fires = world.read_storage::<OnFire>();
+    if on_fires.get(*player_ent).is_some() {
+        return true;
+    }
+    let positions = world.read_storage::<Position>();
+    let player_position = positions.get(*player_ent).unwrap();
+    let dungeon = world.fetch::<Dungeon>();
+    let level = dungeon.get_level(player_position.level).unwrap();
+    let idx = player_position.idx as i32;
+    iter::once(idx)
+        .chain(
+            level_utils::get_neighbors_for_idx(level.width as i32, idx)
+                .iter()
+                .copied(),
+        )
+        .filter(|idx| *idx >= 0 && !level_utils::idx_not_in_map(level, *idx as usize))
+        .any(|idx| {
+            level.tile_content[idx as usize]
+                .iter()
+                .any(|entity| on_fires.get(*entity).is_some())
+        })
+}
+
+pub fn get_next_step(world: &World) -> RestStep {
+    if is_burning(world) {
+        return RestStep::Burning;
+    }
+    let player_ent = world.fetch::<Entity>();
+    let hungers = world.read_storage::<Hunger>();
+    if let Some(hunger) = hungers.get(*player_ent) {
+        if hunger.get_state() != HungerState::Fed {
+            return RestStep::Hungry;
+        }
+    }
+    let combat_stats = world.read_storage::<CombatStats>();
+    let player_stats = combat_stats.get(*player_ent).unwrap();
+    match player_stats.hp >= player_stats.max_hp {
+        true => RestStep::Healed,
+        false => RestStep::Rest,
+    }
+}
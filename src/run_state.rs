@@ -1,4 +1,7 @@
-use crate::{components::equipable::EquipmentPositions, user_actions::MapAction};
+use crate::{
+    components::equipable::EquipmentPositions, control::Control, options::OptionsTab,
+    services::game_log::LogCategory, user_actions::MapAction,
+};
 use specs::Entity;
 
 
@@ -28,6 +31,8 @@ pub enum RunState {
     ItemUseTargeting {
         range: u32,
         item: Entity,
+        // set once the cursor is moved with a gamepad, otherwise the mouse picks the target
+        cursor: Option<usize>,
     },
     InteractAtIdx {
         idx: usize,
@@ -44,6 +49,9 @@ pub enum RunState {
     InteractiveEntityTargeting {
         target_idx: usize,
     },
+    ExamineMode {
+        idx: usize,
+    },
     InteractMenu {
         highlighted: usize,
         target: Entity,
@@ -55,6 +63,9 @@ pub enum RunState {
     InventoryMenu {
         highlighted: usize,
     },
+    LandmarkMenu {
+        highlighted: usize,
+    },
     DropItemMenu {
         highlighted: usize,
     },
@@ -76,6 +87,10 @@ pub enum RunState {
     ActionMenu {
         highlighted: usize,
     },
+    RepeatMenu {
+        highlighted: usize,
+        action: MapAction,
+    },
     #[cfg(debug_assertions)]
     DebugMenu {
         highlighted: usize,
@@ -84,9 +99,24 @@ pub enum RunState {
     IntroScreen,
     FailureScreen,
     SuccessScreen,
-    OptionsScreen { highlighted: usize },
-    SetKey { action: MapAction, highlighted: usize },
+    OptionsScreen { tab: OptionsTab, highlighted: usize },
+    // resetting the controls also clears any recorded macros, so it has to be confirmed
+    ResetOptionsMenu {
+        tab: OptionsTab,
+        highlighted: usize,
+        option_highlighted: usize,
+    },
+    SetKey {
+        action: MapAction,
+        highlighted: usize,
+        conflict: Option<Control>,
+    },
     CreditsScreen,
+    MessageLogScreen {
+        offset: usize,
+        filter: Option<LogCategory>,
+    },
+    OverviewScreen,
     LoadingScreen {
         count_down: u32,
     },
@@ -5,19 +5,24 @@ pub mod screen_failure;
 pub mod screen_intro;
 pub mod screen_loading;
 pub mod screen_main_menu;
+pub mod screen_map_examine;
 pub mod screen_map_generic;
 pub mod screen_map_interact_menu;
 pub mod screen_map_interact_target;
 pub mod screen_map_menu;
 pub mod screen_map_nested_menu;
+pub mod screen_map_overview;
 pub mod screen_map_targeting;
+pub mod screen_message_log;
 pub mod screen_new_game;
 pub mod screen_options;
 pub mod screen_saving;
 pub mod screen_set_key;
 pub mod screen_success;
+pub mod sprite_sheet;
 mod ui;
 pub mod utils;
+pub mod viewport;
 
 pub use constants::{MAP_HEIGHT, MAP_WIDTH, SCREEN_HEIGHT, SCREEN_PADDING, SCREEN_WIDTH};
 pub use screen_credits::ScreenCredits;
@@ -26,12 +31,15 @@ pub use screen_failure::ScreenFailure;
 pub use screen_intro::ScreenIntro;
 pub use screen_loading::ScreenLoading;
 pub use screen_main_menu::ScreenMainMenu;
+pub use screen_map_examine::ScreenMapExamine;
 pub use screen_map_generic::ScreenMapGeneric;
 pub use screen_map_interact_menu::ScreenMapInteractMenu;
 pub use screen_map_interact_target::ScreenMapInteractTarget;
 pub use screen_map_menu::ScreenMapMenu;
 pub use screen_map_nested_menu::ScreenMapNestedMenu;
+pub use screen_map_overview::ScreenMapOverview;
 pub use screen_map_targeting::ScreenMapTargeting;
+pub use screen_message_log::ScreenMessageLog;
 pub use screen_new_game::ScreenNewGame;
 pub use screen_options::ScreenOptions;
 pub use screen_saving::ScreenSaving;
@@ -0,0 +1,159 @@
+use super::sprite_sheet::{highlight_cell, RenderMode};
+use super::utils::{get_render_data, get_render_offset};
+use super::{
+    ui::{
+        ui_map::UIMap,
+        ui_tooltip::{UIToolTip, UIToolTipPosition},
+    },
+    SCREEN_WIDTH,
+};
+use crate::components::{
+    CombatStats, Equipment, Hidden, Hiding, Info, Name, OnFire, Paralyzed, Position, Viewshed,
+};
+use crate::copy;
+use crate::dungeon::{dungeon::Dungeon, level_utils};
+use crate::ui_components::{utils::split_to_lines, Style, UITextLine};
+use rltk::{Rltk, BLACK, RGB, YELLOW};
+use specs::{Entity, World, WorldExt};
+
+const DESCRIPTION_WIDTH: u32 = 40;
+
+fn get_entity_lines(world: &World, entity: Entity) -> Vec<String> {
+    let names = world.read_storage::<Name>();
+    let infos = world.read_storage::<Info>();
+    let combat_stats = world.read_storage::<CombatStats>();
+    let equipment = world.read_storage::<Equipment>();
+    let on_fire = world.read_storage::<OnFire>();
+    let paralyzed = world.read_storage::<Paralyzed>();
+    let hidden = world.read_storage::<Hidden>();
+    let mut lines = vec![];
+    if let Some(name) = names.get(entity) {
+        lines.push(name.name.clone());
+    }
+    if let Some(info) = infos.get(entity) {
+        lines.extend(
+            split_to_lines(&info.description, DESCRIPTION_WIDTH)
+                .into_iter()
+                .map(|line| format!("  {}", line)),
+        );
+    }
+    if let Some(stats) = combat_stats.get(entity) {
+        lines.push(format!("  Health: {}", stats.get_health_description()));
+    }
+    if let Some(equipment) = equipment.get(entity) {
+        let wielding: Vec<String> = [equipment.dominant_hand, equipment.off_hand]
+            .iter()
+            .flatten()
+            .filter_map(|item| names.get(*item))
+            .map(|name| name.name.clone())
+            .collect();
+        if !wielding.is_empty() {
+            lines.push(format!("  Wielding: {}", wielding.join(", ")));
+        }
+    }
+    let mut statuses = vec![];
+    if on_fire.get(entity).is_some() {
+        statuses.push("On fire");
+    }
+    if paralyzed.get(entity).is_some() {
+        statuses.push("Paralyzed");
+    }
+    if hidden.get(entity).is_some() {
+        statuses.push("Hidden");
+    }
+    if !statuses.is_empty() {
+        lines.push(format!("  Status: {}", statuses.join(", ")));
+    }
+    lines
+}
+
+fn get_examine_lines(world: &World, idx: usize) -> Vec<String> {
+    let player_ent = world.fetch::<Entity>();
+    let positions = world.read_storage::<Position>();
+    let player_position = positions.get(*player_ent).unwrap();
+    let viewsheds = world.read_storage::<Viewshed>();
+    let player_viewshed = viewsheds.get(*player_ent).unwrap();
+    let dungeon = world.fetch::<Dungeon>();
+    let level = dungeon.get_level(player_position.level).unwrap();
+    let hidden = world.read_storage::<Hidden>();
+    let hiding = world.read_storage::<Hiding>();
+    if !level.revealed_tiles[idx] {
+        return vec![copy::EXAMINE_UNEXPLORED.to_string()];
+    }
+    let mut lines = vec![level.tiles[idx].get_name().to_string()];
+    if !player_viewshed.visible_tiles.contains(&idx) {
+        lines.push(copy::EXAMINE_NOT_VISIBLE.to_string());
+        lines.extend(
+            level
+                .remembered_entities
+                .iter()
+                .filter(|r| r.idx == idx)
+                .map(|r| format!("{} {}", copy::EXAMINE_REMEMBERED, r.name)),
+        );
+        return lines;
+    }
+    level.tile_content[idx]
+        .iter()
+        .filter(|entity| {
+            let visible_to_player = match hidden.get(**entity) {
+                Some(h) => h.found_by.contains(&*player_ent),
+                None => true,
+            };
+            let hiding = hiding.get(**entity).is_some() && **entity != *player_ent;
+            visible_to_player && !hiding
+        })
+        .for_each(|entity| lines.extend(get_entity_lines(world, *entity)));
+    lines
+}
+
+pub struct ScreenMapExamine {
+    idx: usize,
+}
+
+impl ScreenMapExamine {
+    pub fn new(idx: usize) -> Self {
+        Self { idx }
+    }
+
+    pub fn draw(&self, ctx: &mut Rltk, world: &mut World) {
+        ctx.cls();
+        let player_ent = world.fetch::<Entity>();
+        let positions = world.read_storage::<Position>();
+        let player_position = positions.get(*player_ent).unwrap();
+        let dungeon = world.fetch::<Dungeon>();
+        let level = dungeon.get_level(player_position.level).unwrap();
+        let render_data = get_render_data(world);
+        let (center_x, center_y) = level_utils::idx_xy(level.width as u32, self.idx);
+        let render_offset = get_render_offset(center_x, center_y);
+        let viewsheds = world.read_storage::<Viewshed>();
+        let player_viewshed = viewsheds.get(*player_ent).unwrap();
+        UIMap::new(
+            level,
+            &render_data,
+            render_offset,
+            &player_viewshed.visible_tiles,
+            *world.fetch::<RenderMode>(),
+        )
+        .draw(ctx);
+        UITextLine::new(
+            1,
+            0,
+            copy::CTA_EXAMINE,
+            Some(Style {
+                fg: YELLOW,
+                bg: BLACK,
+            }),
+        )
+        .draw(ctx);
+        let focus_x = center_x - render_offset.0;
+        let focus_y = center_y - render_offset.1;
+        highlight_cell(ctx, focus_x, focus_y, RGB::named(YELLOW));
+        let tool_tip_pos = match focus_x > (SCREEN_WIDTH / 2) as i32 {
+            true => UIToolTipPosition::Left,
+            false => UIToolTipPosition::Right,
+        };
+        let examine_lines = get_examine_lines(world, self.idx);
+        let tool_tip_lines: Box<[&str]> = examine_lines.iter().map(String::as_str).collect();
+        UIToolTip::new(focus_x, focus_y, tool_tip_pos, &tool_tip_lines).draw(ctx);
+    }
+}
@@ -1,11 +1,20 @@
-use super::ui::ui_map_screen::UIMapScreen;
-use super::utils::{get_render_data, get_render_offset, get_render_offset_for_xy};
+use super::constants::MAP_WIDTH;
+use super::sprite_sheet::RenderMode;
+use super::ui::{ui_level_overview::UILevelOverview, ui_map_screen::UIMapScreen};
+use super::utils::{
+    get_player_view_center, get_render_data, get_render_offset, get_render_offset_for_xy,
+};
+use super::viewport::Viewport;
 use crate::components::{CombatStats, Hidden, Hiding, Name, Position, Viewshed};
 use crate::dungeon::{dungeon::Dungeon, level_utils};
-use crate::services::GameLog;
-use rltk::Rltk;
+use crate::services::{game_log::MessageVerbosity, GameLog};
+use crate::ui_components::UIBox;
+use rltk::{Rltk, BLACK, WHITE};
 use specs::{Entity, Join, World, WorldExt};
 
+const MINIMAP_SCALE_X: i32 = 5;
+const MINIMAP_SCALE_Y: i32 = 6;
+
 pub struct ScreenMapGeneric {
     offset_x: i32,
     offset_y: i32,
@@ -33,7 +42,7 @@ impl ScreenMapGeneric {
         let player_viewshed = viewsheds.get(*player_ent).unwrap();
         let level = dungeon.levels.get(&player_position.level).unwrap();
         let level_width = level.width as u32;
-        let (center_x, center_y) = level_utils::idx_xy(level_width, player_position.idx);
+        let (center_x, center_y) = get_player_view_center(world);
         let center_x = center_x + self.offset_x;
         let center_y = center_y + self.offset_y;
         let render_offset = get_render_offset(center_x, center_y);
@@ -73,7 +82,9 @@ impl ScreenMapGeneric {
         };
         let tool_tip_lines: Box<[&str]> = tool_tip_lines.iter().map(|line| line.as_str()).collect();
         let render_data = get_render_data(world);
-        let log_entries = log.entries.iter().map(String::as_str).collect();
+        let log_entries = log
+            .get_entries_for_verbosity(*world.fetch::<MessageVerbosity>())
+            .into_boxed_slice();
         ctx.cls();
         UIMapScreen::new(
             mouse_x,
@@ -86,8 +97,28 @@ impl ScreenMapGeneric {
             level,
             &render_data,
             render_offset,
-            &player_viewshed.visible_tiles
+            &player_viewshed.visible_tiles,
+            *world.fetch::<RenderMode>(),
         )
         .draw(ctx);
+        // boxed in the top right corner of the map, clear of the message log
+        if world.fetch::<Viewport>().show_minimap {
+            let (width, height) =
+                UILevelOverview::get_size(level, MINIMAP_SCALE_X, MINIMAP_SCALE_Y);
+            let box_x = MAP_WIDTH as i32 - 2 - width;
+            UIBox::new(box_x, 0, width as u8 + 1, height as u8 + 1, WHITE, BLACK).draw(ctx);
+            let minimap_x = box_x + 1;
+            let minimap_y = 1;
+            UILevelOverview::new(
+                minimap_x,
+                minimap_y,
+                MINIMAP_SCALE_X,
+                MINIMAP_SCALE_Y,
+                level,
+                &render_data,
+                player_position.idx,
+            )
+            .draw(ctx);
+        }
     }
 }
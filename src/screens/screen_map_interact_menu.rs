@@ -1,12 +1,12 @@
 use std::fmt::Display;
 
+use super::sprite_sheet::RenderMode;
 use super::ui::ui_map::UIMap;
-use super::utils::{get_render_data, get_render_offset};
+use super::utils::{get_player_view_center, get_render_data, get_render_offset};
 use crate::components::{Position, Viewshed};
 use crate::dungeon::{
     constants::{MAP_HEIGHT, MAP_WIDTH},
     dungeon::Dungeon,
-    level_utils,
 };
 use crate::menu::MenuOption;
 use crate::ui_components::ui_dynamic_menu::UIDynamicMenu;
@@ -41,9 +41,7 @@ impl<'a, T: Display + Copy> ScreenMapInteractMenu<'a, T> {
         let dungeon = world.fetch::<Dungeon>();
         let level = dungeon.levels.get(&player_position.level).unwrap();
         let render_data = get_render_data(world);
-        let positions = world.read_storage::<Position>();
-        let player_position = positions.get(*player_ent).unwrap();
-        let (center_x, center_y) = level_utils::idx_xy(level.width as u32, player_position.idx);
+        let (center_x, center_y) = get_player_view_center(world);
         let render_offset = get_render_offset(center_x, center_y);
         let viewsheds = world.read_storage::<Viewshed>();
         let player_viewshed = viewsheds.get(*player_ent).unwrap();
@@ -52,6 +50,7 @@ impl<'a, T: Display + Copy> ScreenMapInteractMenu<'a, T> {
             &render_data,
             render_offset,
             &player_viewshed.visible_tiles,
+            *world.fetch::<RenderMode>(),
         )
         .draw(ctx);
 
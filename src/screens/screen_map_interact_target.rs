@@ -1,3 +1,4 @@
+use super::sprite_sheet::{highlight_cell, RenderMode};
 use super::utils::{get_render_data, get_render_offset};
 use super::{
     ui::{
@@ -9,7 +10,7 @@ use super::{
 use crate::components::{Name, Position, Viewshed};
 use crate::dungeon::{dungeon::Dungeon, level_utils};
 use crate::ui_components::{Style, UITextLine};
-use rltk::{Rltk, BLACK, YELLOW};
+use rltk::{Rltk, BLACK, RGB, YELLOW};
 use specs::{Entity, World, WorldExt};
 
 pub struct ScreenMapInteractTarget<'a> {
@@ -42,6 +43,7 @@ impl<'a> ScreenMapInteractTarget<'a> {
             &render_data,
             render_offset,
             &player_viewshed.visible_tiles,
+            *world.fetch::<RenderMode>(),
         )
         .draw(ctx);
         UITextLine::new(
@@ -57,7 +59,7 @@ impl<'a> ScreenMapInteractTarget<'a> {
         .draw(ctx);
         let focus_x = center_x as i32 - render_offset.0;
         let focus_y = center_y as i32 - render_offset.1;
-        ctx.set_bg(focus_x, focus_y, YELLOW);
+        highlight_cell(ctx, focus_x, focus_y, RGB::named(YELLOW));
         let tool_tip_pos = match focus_x > (SCREEN_WIDTH / 2) as i32 {
             true => UIToolTipPosition::Left,
             false => UIToolTipPosition::Right,
@@ -1,15 +1,15 @@
 use std::fmt::Display;
 
+use super::sprite_sheet::RenderMode;
 use super::ui::{ui_hud::UIHud, ui_map::UIMap};
-use super::utils::{get_render_data, get_render_offset};
+use super::utils::{get_player_view_center, get_render_data, get_render_offset};
 use crate::components::{CombatStats, Position, Viewshed};
 use crate::dungeon::{
     constants::{MAP_HEIGHT, MAP_WIDTH},
     dungeon::Dungeon,
-    level_utils,
 };
 use crate::menu::MenuOption;
-use crate::services::GameLog;
+use crate::services::{game_log::MessageVerbosity, GameLog};
 use crate::ui_components::ui_dynamic_menu::UIDynamicMenu;
 use rltk::Rltk;
 use specs::{Entity, World, WorldExt};
@@ -44,11 +44,20 @@ impl<'a, T: Display + Copy> ScreenMapMenu<'a, T> {
         let render_data = get_render_data(world);
         let positions = world.read_storage::<Position>();
         let player_position = positions.get(*player_ent).unwrap();
-        let (center_x, center_y) = level_utils::idx_xy(level.width as u32, player_position.idx);
+        let (center_x, center_y) = get_player_view_center(world);
         let render_offset = get_render_offset(center_x, center_y);
 
-        UIMap::new(level, &render_data, render_offset, &player_viewshed.visible_tiles).draw(ctx);
-        let log_entries = log.entries.iter().map(String::as_str).collect();
+        UIMap::new(
+            level,
+            &render_data,
+            render_offset,
+            &player_viewshed.visible_tiles,
+            *world.fetch::<RenderMode>(),
+        )
+        .draw(ctx);
+        let log_entries = log
+            .get_entries_for_verbosity(*world.fetch::<MessageVerbosity>())
+            .into_boxed_slice();
 
         UIHud::new(
             player_position.level,
@@ -1,15 +1,15 @@
 use std::fmt::Display;
 
+use super::sprite_sheet::RenderMode;
 use super::ui::{ui_hud::UIHud, ui_map::UIMap};
-use super::utils::{get_render_data, get_render_offset};
+use super::utils::{get_player_view_center, get_render_data, get_render_offset};
 use crate::components::{CombatStats, Position, Viewshed};
 use crate::dungeon::{
     constants::{MAP_HEIGHT, MAP_WIDTH},
     dungeon::Dungeon,
-    level_utils,
 };
 use crate::menu::MenuOption;
-use crate::services::GameLog;
+use crate::services::{game_log::MessageVerbosity, GameLog};
 use crate::ui_components::{UILineVertical, UIMenuBox, UIMenuItemGroup, UIParagraph};
 use rltk::Rltk;
 use specs::{Entity, World, WorldExt};
@@ -58,7 +58,7 @@ impl<'a, T: ToString, S: ToString> ScreenMapNestedMenu<'a, T, S> {
         let render_data = get_render_data(world);
         let positions = world.read_storage::<Position>();
         let player_position = positions.get(*player_ent).unwrap();
-        let (center_x, center_y) = level_utils::idx_xy(level.width as u32, player_position.idx);
+        let (center_x, center_y) = get_player_view_center(world);
         let render_offset = get_render_offset(center_x, center_y);
         let viewsheds = world.read_storage::<Viewshed>();
         let player_viewshed = viewsheds.get(*player_ent).unwrap();
@@ -67,9 +67,12 @@ impl<'a, T: ToString, S: ToString> ScreenMapNestedMenu<'a, T, S> {
             &render_data,
             render_offset,
             &player_viewshed.visible_tiles,
+            *world.fetch::<RenderMode>(),
         )
         .draw(ctx);
-        let log_entries = log.entries.iter().map(String::as_str).collect();
+        let log_entries = log
+            .get_entries_for_verbosity(*world.fetch::<MessageVerbosity>())
+            .into_boxed_slice();
 
         UIHud::new(
             player_position.level,
@@ -0,0 +1,66 @@
+use super::constants::{SCREEN_HEIGHT, SCREEN_PADDING, SCREEN_WIDTH};
+use super::ui::ui_level_overview::UILevelOverview;
+use super::utils::get_render_data;
+use crate::components::Position;
+use crate::copy;
+use crate::dungeon::dungeon::Dungeon;
+use crate::ui_components::{ui_paragraph::UIParagraph, Style, UITextLine};
+use rltk::{Rltk, BLACK, YELLOW};
+use specs::{Entity, World, WorldExt};
+
+const OVERVIEW_TOP: i32 = SCREEN_PADDING as i32 + 3;
+const OVERVIEW_WIDTH: i32 = (SCREEN_WIDTH - SCREEN_PADDING * 2) as i32;
+const OVERVIEW_HEIGHT: i32 = SCREEN_HEIGHT as i32 - SCREEN_PADDING as i32 - OVERVIEW_TOP;
+
+fn get_scale(size: i32, available: i32) -> i32 {
+    ((size + available - 1) / available).max(1)
+}
+
+pub struct ScreenMapOverview {}
+
+impl ScreenMapOverview {
+    pub fn new() -> Self {
+        Self {}
+    }
+
+    pub fn draw(&self, ctx: &mut Rltk, world: &mut World) {
+        ctx.cls();
+        let player_ent = world.fetch::<Entity>();
+        let positions = world.read_storage::<Position>();
+        let player_position = positions.get(*player_ent).unwrap();
+        let dungeon = world.fetch::<Dungeon>();
+        let level = dungeon.get_level(player_position.level).unwrap();
+        let render_data = get_render_data(world);
+        UITextLine::new(
+            SCREEN_PADDING as i32,
+            SCREEN_PADDING as i32,
+            &player_position.level.get_name(),
+            Some(Style {
+                fg: YELLOW,
+                bg: BLACK,
+            }),
+        )
+        .draw(ctx);
+        UIParagraph::new(
+            SCREEN_PADDING as i32,
+            SCREEN_PADDING as i32 + 1,
+            OVERVIEW_WIDTH as u32,
+            copy::CTA_OVERVIEW,
+        )
+        .draw(ctx);
+        // keep the aspect ratio by using the larger of the two scales on both axes
+        let scale = get_scale(level.width as i32, OVERVIEW_WIDTH)
+            .max(get_scale(level.height as i32, OVERVIEW_HEIGHT));
+        let (width, height) = UILevelOverview::get_size(level, scale, scale);
+        UILevelOverview::new(
+            SCREEN_PADDING as i32 + (OVERVIEW_WIDTH - width) / 2,
+            OVERVIEW_TOP + (OVERVIEW_HEIGHT - height) / 2,
+            scale,
+            scale,
+            level,
+            &render_data,
+            player_position.idx,
+        )
+        .draw(ctx);
+    }
+}
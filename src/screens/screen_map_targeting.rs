@@ -1,11 +1,12 @@
+use super::sprite_sheet::{highlight_cell, RenderMode};
 use super::ui::ui_hud::UIHud;
 use super::ui::ui_map::UIMap;
 use super::ui::ui_mouse_pos::UIMousePos;
-use super::utils::{get_render_data, get_render_offset};
+use super::utils::{get_player_view_center, get_render_data, get_render_offset};
 use crate::components::{CombatStats, Position, Viewshed};
 use crate::dungeon::{dungeon::Dungeon, level_utils};
 use crate::ranged;
-use crate::services::GameLog;
+use crate::services::{game_log::MessageVerbosity, GameLog};
 use crate::ui_components::{Style, UITextLine};
 use rltk::{Rltk, BLACK, BLUE, CYAN, RGB, YELLOW};
 use specs::{Entity, World, WorldExt};
@@ -13,12 +14,23 @@ use specs::{Entity, World, WorldExt};
 pub struct ScreenMapTargeting {
     range: u32,
     target: Option<usize>,
+    cursor: Option<usize>,
     cta: Option<String>,
 }
 
 impl ScreenMapTargeting {
-    pub fn new(range: u32, target: Option<usize>, cta: Option<String>) -> Self {
-        Self { range, target, cta }
+    pub fn new(
+        range: u32,
+        target: Option<usize>,
+        cursor: Option<usize>,
+        cta: Option<String>,
+    ) -> Self {
+        Self {
+            range,
+            target,
+            cursor,
+            cta,
+        }
     }
     pub fn draw(&self, ctx: &mut Rltk, world: &mut World) {
         ctx.cls();
@@ -33,7 +45,7 @@ impl ScreenMapTargeting {
         let dungeon = world.fetch::<Dungeon>();
         let level = dungeon.levels.get(&player_position.level).unwrap();
         let render_data = get_render_data(world);
-        let (center_x, center_y) = level_utils::idx_xy(level.width as u32, player_position.idx);
+        let (center_x, center_y) = get_player_view_center(world);
         let render_offset = get_render_offset(center_x, center_y);
 
         UIMap::new(
@@ -41,9 +53,12 @@ impl ScreenMapTargeting {
             &render_data,
             render_offset,
             &player_viewshed.visible_tiles,
+            *world.fetch::<RenderMode>(),
         )
         .draw(ctx);
-        let log_entries = log.entries.iter().map(String::as_str).collect();
+        let log_entries = log
+            .get_entries_for_verbosity(*world.fetch::<MessageVerbosity>())
+            .into_boxed_slice();
         UIHud::new(
             player_position.level,
             player_stats.hp,
@@ -66,13 +81,32 @@ impl ScreenMapTargeting {
         let visible_tiles = ranged::get_visible_tiles_in_range(world, self.range);
         visible_tiles.iter().for_each(|tile| {
             let (x, y) = level_utils::idx_xy(level.width as u32, *tile);
-            ctx.set_bg(x - render_offset.0, y - render_offset.1, RGB::named(BLUE));
+            highlight_cell(
+                ctx,
+                x - render_offset.0,
+                y - render_offset.1,
+                RGB::named(BLUE),
+            );
         });
         let (mouse_x, mouse_y) = ctx.mouse_pos();
         UIMousePos::new(mouse_x, mouse_y).draw(ctx);
+        if let Some(cursor) = self.cursor {
+            let (x, y) = level_utils::idx_xy(level.width as u32, cursor);
+            highlight_cell(
+                ctx,
+                x - render_offset.0,
+                y - render_offset.1,
+                RGB::named(YELLOW),
+            )
+        }
         if let Some(target) = self.target {
             let (x, y) = level_utils::idx_xy(level.width as u32, target);
-            ctx.set_bg(x - render_offset.0, y - render_offset.1, RGB::named(CYAN))
+            highlight_cell(
+                ctx,
+                x - render_offset.0,
+                y - render_offset.1,
+                RGB::named(CYAN),
+            )
         }
     }
 }
@@ -0,0 +1,93 @@
+use super::constants::{SCREEN_HEIGHT, SCREEN_PADDING, SCREEN_WIDTH};
+use crate::copy;
+use crate::services::game_log::{LogCategory, LogEntry};
+use crate::ui_components::{ui_paragraph::UIParagraph, Style, UITextLine};
+use rltk::{Rltk, BLACK, GREY, YELLOW};
+
+const LOG_TOP: i32 = SCREEN_PADDING as i32 + 4;
+pub const MESSAGE_LOG_PAGE_SIZE: usize = (SCREEN_HEIGHT - SCREEN_PADDING * 2) as usize - 5;
+
+pub struct ScreenMessageLog<'a> {
+    entries: &'a Vec<&'a LogEntry>,
+    offset: usize,
+    filter: Option<LogCategory>,
+}
+
+impl<'a> ScreenMessageLog<'a> {
+    pub fn new(entries: &'a Vec<&'a LogEntry>, offset: usize, filter: Option<LogCategory>) -> Self {
+        Self {
+            entries,
+            offset,
+            filter,
+        }
+    }
+
+    pub fn draw(&self, ctx: &mut Rltk) {
+        ctx.cls();
+        UIParagraph::new(
+            SCREEN_PADDING as i32,
+            SCREEN_PADDING as i32,
+            (SCREEN_WIDTH - SCREEN_PADDING * 2) as u32,
+            "Message Log",
+        )
+        .draw(ctx);
+        UIParagraph::new(
+            SCREEN_PADDING as i32,
+            SCREEN_PADDING as i32 + 1,
+            (SCREEN_WIDTH - SCREEN_PADDING * 2) as u32,
+            copy::CTA_MESSAGE_LOG,
+        )
+        .draw(ctx);
+        let filter_name = match self.filter {
+            Some(category) => category.get_name(),
+            None => "All",
+        };
+        let summary = format!(
+            "Showing: {}    {} - {} of {}",
+            filter_name,
+            (self.offset + 1).min(self.entries.len()),
+            (self.offset + MESSAGE_LOG_PAGE_SIZE).min(self.entries.len()),
+            self.entries.len()
+        );
+        UITextLine::new(
+            SCREEN_PADDING as i32,
+            SCREEN_PADDING as i32 + 2,
+            summary,
+            Some(Style {
+                fg: YELLOW,
+                bg: BLACK,
+            }),
+        )
+        .draw(ctx);
+        for (i, entry) in self
+            .entries
+            .iter()
+            .skip(self.offset)
+            .take(MESSAGE_LOG_PAGE_SIZE)
+            .enumerate()
+        {
+            let y = LOG_TOP + i as i32;
+            let turn = format!("[{:>5}]", entry.turn);
+            UITextLine::new(
+                SCREEN_PADDING as i32,
+                y,
+                &turn,
+                Some(Style {
+                    fg: GREY,
+                    bg: BLACK,
+                }),
+            )
+            .draw(ctx);
+            UITextLine::new(
+                SCREEN_PADDING as i32 + turn.len() as i32 + 1,
+                y,
+                &entry.message,
+                Some(Style {
+                    fg: entry.color,
+                    bg: BLACK,
+                }),
+            )
+            .draw(ctx);
+        }
+    }
+}
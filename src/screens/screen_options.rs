@@ -1,23 +1,34 @@
 use std::fmt::Display;
 
-use crate::ui_components::{ui_paragraph::UIParagraph, UIMenuItemGroup};
+use crate::ui_components::{ui_paragraph::UIParagraph, UIMenuItemGroup, UIMenuItemGroupHorizontal};
 use crate::{
     menu::MenuOption,
-    screens::constants::{SCREEN_PADDING, SCREEN_WIDTH},
+    screens::constants::{SCREEN_HEIGHT, SCREEN_PADDING, SCREEN_WIDTH},
 };
 use rltk::Rltk;
 
+const OPTIONS_TOP: i32 = SCREEN_PADDING as i32 + 5;
+pub const OPTIONS_PER_PAGE: usize =
+    (SCREEN_HEIGHT - SCREEN_PADDING) as usize - OPTIONS_TOP as usize;
+
 pub struct ScreenOptions<'a, T: Display + Copy> {
     title: &'a str,
     cta: &'a str,
+    tab_options: Box<[&'a MenuOption<&'a str>]>,
     control_options: Box<[&'a MenuOption<T>]>,
 }
 
 impl<'a, T: Display + Copy> ScreenOptions<'a, T> {
-    pub fn new(title: &'a str, cta: &'a str, control_options: Box<[&'a MenuOption<T>]>) -> Self {
+    pub fn new(
+        title: &'a str,
+        cta: &'a str,
+        tab_options: Box<[&'a MenuOption<&'a str>]>,
+        control_options: Box<[&'a MenuOption<T>]>,
+    ) -> Self {
         Self {
             title,
             cta,
+            tab_options,
             control_options,
         }
     }
@@ -40,9 +51,16 @@ impl<'a, T: Display + Copy> ScreenOptions<'a, T> {
         )
         .draw(ctx);
 
-        UIMenuItemGroup::new(
+        UIMenuItemGroupHorizontal::new(
             SCREEN_PADDING as i32,
             SCREEN_PADDING as i32 + 3,
+            &self.tab_options,
+        )
+        .draw(ctx);
+
+        UIMenuItemGroup::new(
+            SCREEN_PADDING as i32,
+            OPTIONS_TOP,
             &self.control_options,
             true,
         )
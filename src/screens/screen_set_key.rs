@@ -1,5 +1,6 @@
 use crate::ui_components::ui_paragraph::UIParagraph;
 use crate::{
+    control::Control,
     screens::constants::{SCREEN_PADDING, SCREEN_WIDTH},
     user_actions::MapAction,
 };
@@ -7,22 +8,57 @@ use rltk::Rltk;
 
 pub struct ScreenSetKey<'a> {
     action: &'a MapAction,
+    bindings: &'a [String],
+    conflict: Option<&'a Control>,
+    conflicts: &'a [String],
 }
 
 impl<'a> ScreenSetKey<'a> {
-    pub fn new(action: &'a MapAction) -> Self {
-        Self { action }
+    pub fn new(
+        action: &'a MapAction,
+        bindings: &'a [String],
+        conflict: Option<&'a Control>,
+        conflicts: &'a [String],
+    ) -> Self {
+        Self {
+            action,
+            bindings,
+            conflict,
+            conflicts,
+        }
     }
 
     pub fn draw(&self, ctx: &mut Rltk) {
         ctx.cls();
-        let text = format!("Set key for {}. Escape to exit.", self.action);
+        let width = (SCREEN_WIDTH - SCREEN_PADDING * 2) as u32;
+        let bindings = match self.bindings.is_empty() {
+            true => String::from("nothing"),
+            false => self.bindings.join(", "),
+        };
+        let text = format!(
+            "Press a key or mouse button to add a binding for {}, it is currently bound to {}. Escape to exit.",
+            self.action, bindings
+        );
         UIParagraph::new(
             SCREEN_PADDING as i32,
             SCREEN_PADDING as i32,
-            (SCREEN_WIDTH - SCREEN_PADDING * 2) as u32,
+            width,
             text.as_str(),
         )
         .draw(ctx);
+        if let Some(conflict) = self.conflict {
+            let text = format!(
+                "{} is already used for {}. Press it again to bind it anyway.",
+                conflict,
+                self.conflicts.join(", ")
+            );
+            UIParagraph::new(
+                SCREEN_PADDING as i32,
+                SCREEN_PADDING as i32 + 4,
+                width,
+                text.as_str(),
+            )
+            .draw(ctx);
+        }
     }
 }
@@ -0,0 +1,162 @@
+use crate::dungeon::tile_type::TileType;
+use rltk::{Rltk, RGB};
+use serde::{Deserialize, Serialize};
+
+pub const SPRITE_SHEET_FONT: &str = "tiles.png";
+// sprites are drawn underneath the sparse text console, so anything without a
+// sprite falls back to its CP437 glyph drawn over the top
+pub const SPRITE_CONSOLE: usize = 0;
+pub const TEXT_CONSOLE: usize = 1;
+
+// indexes into resources/tiles.png, tiles are drawn in colour and tinted by
+// visibility, entities are drawn in greys and tinted by their renderable
+const SPRITE_FLOOR: u16 = 0;
+const SPRITE_DOORWAY: u16 = 1;
+const SPRITE_DOWN_STAIRS: u16 = 2;
+const SPRITE_UP_STAIRS: u16 = 3;
+const SPRITE_EXIT: u16 = 4;
+const SPRITE_COLUMN: u16 = 5;
+const SPRITE_WATER_DEEP: u16 = 6;
+// one sprite per autotile mask, see `get_wall_mask` and `get_ledge_mask`
+const SPRITE_WALLS_START: u16 = 16;
+const SPRITE_LEDGES_START: u16 = 32;
+const SPRITE_PLAYER: u16 = 48;
+const SPRITE_GOBLIN: u16 = 49;
+const SPRITE_POTION: u16 = 50;
+const SPRITE_SCROLL: u16 = 51;
+const SPRITE_PILE: u16 = 52;
+const SPRITE_TOOL: u16 = 53;
+const SPRITE_TRAP: u16 = 54;
+const SPRITE_DOOR: u16 = 55;
+const SPRITE_SCONCE: u16 = 56;
+const SPRITE_OBJECTIVE: u16 = 57;
+const SPRITE_DEBRIS: u16 = 58;
+const SPRITE_BARREL: u16 = 59;
+const SPRITE_BED: u16 = 60;
+const SPRITE_STOVE: u16 = 61;
+const SPRITE_WEAPON_RACK: u16 = 62;
+const SPRITE_CHEST: u16 = 63;
+
+const GLYPH_SPRITES: [(char, u16); 16] = [
+    ('@', SPRITE_PLAYER),
+    ('g', SPRITE_GOBLIN),
+    ('i', SPRITE_POTION),
+    (')', SPRITE_SCROLL),
+    ('%', SPRITE_PILE),
+    ('/', SPRITE_TOOL),
+    ('^', SPRITE_TRAP),
+    ('▲', SPRITE_DOOR),
+    ('☼', SPRITE_SCONCE),
+    ('±', SPRITE_OBJECTIVE),
+    ('x', SPRITE_DEBRIS),
+    ('B', SPRITE_BARREL),
+    ('b', SPRITE_BED),
+    ('S', SPRITE_STOVE),
+    ('W', SPRITE_WEAPON_RACK),
+    ('T', SPRITE_CHEST),
+];
+
+#[derive(PartialEq, Serialize, Deserialize, Clone, Copy, Debug)]
+pub enum RenderMode {
+    Ascii,
+    Tileset,
+}
+
+impl RenderMode {
+    pub fn get_name(&self) -> &'static str {
+        match self {
+            Self::Ascii => "ASCII",
+            Self::Tileset => "Tileset",
+        }
+    }
+
+    pub fn get_next(&self) -> Self {
+        match self {
+            Self::Ascii => Self::Tileset,
+            Self::Tileset => Self::Ascii,
+        }
+    }
+}
+
+// fonts bundled with rltk, the window is sized to fit the font's glyphs so a
+// change only takes effect on restart
+#[derive(PartialEq, Serialize, Deserialize, Clone, Copy, Debug)]
+pub enum TextFont {
+    Terminal8x8,
+    Vga8x16,
+}
+
+impl TextFont {
+    pub fn get_name(&self) -> &'static str {
+        match self {
+            Self::Terminal8x8 => "Terminal 8x8",
+            Self::Vga8x16 => "VGA 8x16",
+        }
+    }
+
+    pub fn get_next(&self) -> Self {
+        match self {
+            Self::Terminal8x8 => Self::Vga8x16,
+            Self::Vga8x16 => Self::Terminal8x8,
+        }
+    }
+
+    pub fn get_file(&self) -> &'static str {
+        match self {
+            Self::Terminal8x8 => "terminal8x8.png",
+            Self::Vga8x16 => "vga8x16.png",
+        }
+    }
+
+    pub fn get_tile_dimensions(&self) -> (u32, u32) {
+        match self {
+            Self::Terminal8x8 => (8, 8),
+            Self::Vga8x16 => (8, 16),
+        }
+    }
+}
+
+// walls and ledges join up with their neighbours the same way their box drawing
+// glyphs do, so the mask picks the sprite
+pub fn get_sprite_for_tile(tile: &TileType, mask: u8) -> u16 {
+    match tile {
+        TileType::Floor => SPRITE_FLOOR,
+        TileType::Door => SPRITE_DOORWAY,
+        TileType::DownStairs => SPRITE_DOWN_STAIRS,
+        TileType::UpStairs => SPRITE_UP_STAIRS,
+        TileType::Exit => SPRITE_EXIT,
+        TileType::Column => SPRITE_COLUMN,
+        TileType::WaterDeep => SPRITE_WATER_DEEP,
+        TileType::Wall => SPRITE_WALLS_START + mask as u16,
+        TileType::Ledge => SPRITE_LEDGES_START + mask as u16,
+    }
+}
+
+// glyphs without a sprite, mostly furniture, fall back to CP437
+pub fn get_sprite_for_glyph(glyph: u16) -> Option<u16> {
+    GLYPH_SPRITES
+        .iter()
+        .find(|(character, _)| rltk::to_cp437(*character) == glyph)
+        .map(|(_, sprite)| *sprite)
+}
+
+pub fn set_sprite(ctx: &mut Rltk, x: i32, y: i32, tint: RGB, sprite: u16) {
+    ctx.set_active_console(SPRITE_CONSOLE);
+    ctx.set(x, y, tint, RGB::named(rltk::BLACK), sprite);
+    ctx.set_active_console(TEXT_CONSOLE);
+}
+
+// the text console only has cells where something was printed, so tint the
+// sprite underneath as well for cells that are showing a sprite
+pub fn highlight_cell(ctx: &mut Rltk, x: i32, y: i32, color: RGB) {
+    ctx.set_bg(x, y, color);
+    ctx.set_active_console(SPRITE_CONSOLE);
+    ctx.set_fg(x, y, color);
+    ctx.set_active_console(TEXT_CONSOLE);
+}
+
+pub fn clear_sprites(ctx: &mut Rltk) {
+    ctx.set_active_console(SPRITE_CONSOLE);
+    ctx.cls();
+    ctx.set_active_console(TEXT_CONSOLE);
+}
@@ -1,4 +1,5 @@
 pub mod ui_hud;
+pub mod ui_level_overview;
 pub mod ui_map;
 pub mod ui_map_screen;
 pub mod ui_mouse_pos;
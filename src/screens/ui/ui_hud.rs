@@ -1,4 +1,6 @@
+use crate::dungeon::level_id::LevelId;
 use crate::screens::constants::{MAP_HEIGHT, MAP_WIDTH, SCREEN_HEIGHT};
+use crate::services::game_log::LogEntry;
 use crate::ui_components::{Style, UIBox, UITextLine};
 use rltk::{Rltk, BLACK, RED, RGB, WHITE, YELLOW};
 
@@ -14,16 +16,16 @@ const MESSAGES_LEFT: i32 = HUD_LEFT + 1;
 const MESSAGE_COUNT: u8 = HUD_HEIGHT - 2;
 
 pub struct UIHud<'a, 'b> {
-    depth: u8,
+    level_id: LevelId,
     hp: i32,
     max_hp: i32,
-    messages: &'b Box<[&'a str]>,
+    messages: &'b Box<[&'a LogEntry]>,
 }
 
 impl<'a, 'b> UIHud<'a, 'b> {
-    pub fn new(depth: u8, hp: i32, max_hp: i32, messages: &'b Box<[&'a str]>) -> Self {
+    pub fn new(level_id: LevelId, hp: i32, max_hp: i32, messages: &'b Box<[&'a LogEntry]>) -> Self {
         Self {
-            depth,
+            level_id,
             hp,
             max_hp,
             messages,
@@ -35,7 +37,7 @@ impl<'a, 'b> UIHud<'a, 'b> {
         UITextLine::new(
             2,
             HUD_TOP,
-            &format!("Depth: {}", self.depth),
+            &self.level_id.get_name(),
             Some(Style {
                 fg: YELLOW,
                 bg: BLACK,
@@ -68,7 +70,16 @@ impl<'a, 'b> UIHud<'a, 'b> {
             .enumerate()
             .take(MESSAGE_COUNT as usize)
         {
-            ctx.print(MESSAGES_LEFT, MESSAGES_TOP + i as i32, &message.to_string());
+            UITextLine::new(
+                MESSAGES_LEFT,
+                MESSAGES_TOP + i as i32,
+                &message.message,
+                Some(Style {
+                    fg: message.color,
+                    bg: BLACK,
+                }),
+            )
+            .draw(ctx);
         }
     }
 }
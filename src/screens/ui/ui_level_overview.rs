@@ -0,0 +1,128 @@
+use std::collections::HashMap;
+
+use super::ui_map::RenderData;
+use crate::dungeon::{level::Level, level_utils, tile_type::TileType};
+use rltk::{Rltk, BLACK, CYAN, GREY, RGB, WHITE, YELLOW};
+
+pub struct UILevelOverview<'a> {
+    x: i32,
+    y: i32,
+    scale_x: i32,
+    scale_y: i32,
+    level: &'a Level,
+    renderables: &'a Vec<RenderData>,
+    player_idx: usize,
+}
+
+fn get_tile_priority(level: &Level, idx: usize) -> u8 {
+    if level.get_branch_at_idx(idx).is_some() {
+        return 4;
+    }
+    match level.tiles[idx] {
+        TileType::DownStairs | TileType::UpStairs | TileType::Exit => 4,
+        TileType::Floor | TileType::Door | TileType::WaterDeep => 2,
+        TileType::Wall | TileType::Column | TileType::Ledge => 1,
+    }
+}
+
+fn get_tile_glyph(level: &Level, idx: usize) -> (u16, RGB) {
+    let color = match level.get_branch_at_idx(idx) {
+        Some(_) => RGB::named(CYAN),
+        None => RGB::named(WHITE),
+    };
+    let glyph = match level.tiles[idx] {
+        TileType::Floor | TileType::Door => rltk::to_cp437('.'),
+        TileType::Wall | TileType::Column | TileType::Ledge => rltk::to_cp437('#'),
+        TileType::DownStairs => rltk::to_cp437('>'),
+        TileType::UpStairs => rltk::to_cp437('<'),
+        TileType::Exit => 219,
+        TileType::WaterDeep => 176,
+    };
+    (glyph, color)
+}
+
+impl<'a> UILevelOverview<'a> {
+    pub fn new(
+        x: i32,
+        y: i32,
+        scale_x: i32,
+        scale_y: i32,
+        level: &'a Level,
+        renderables: &'a Vec<RenderData>,
+        player_idx: usize,
+    ) -> Self {
+        Self {
+            x,
+            y,
+            scale_x: scale_x.max(1),
+            scale_y: scale_y.max(1),
+            level,
+            renderables,
+            player_idx,
+        }
+    }
+
+    pub fn get_size(level: &Level, scale_x: i32, scale_y: i32) -> (i32, i32) {
+        let scale_x = scale_x.max(1);
+        let scale_y = scale_y.max(1);
+        (
+            (level.width as i32 + scale_x - 1) / scale_x,
+            (level.height as i32 + scale_y - 1) / scale_y,
+        )
+    }
+
+    fn get_cell(
+        &self,
+        cell_x: i32,
+        cell_y: i32,
+        renderables: &HashMap<usize, &RenderData>,
+    ) -> Option<(u16, RGB)> {
+        let mut best: Option<(u8, u16, RGB)> = None;
+        let max_x = ((cell_x + 1) * self.scale_x).min(self.level.width as i32);
+        let max_y = ((cell_y + 1) * self.scale_y).min(self.level.height as i32);
+        for y in cell_y * self.scale_y..max_y {
+            for x in cell_x * self.scale_x..max_x {
+                let idx = level_utils::xy_idx(self.level.width as u32, x, y) as usize;
+                if idx == self.player_idx {
+                    return Some((rltk::to_cp437('@'), RGB::named(YELLOW)));
+                }
+                if !self.level.revealed_tiles[idx] {
+                    continue;
+                }
+                let candidate = match renderables.get(&idx) {
+                    Some(r) => (3, r.glyph, r.fg),
+                    None => {
+                        let (glyph, color) = get_tile_glyph(self.level, idx);
+                        (get_tile_priority(self.level, idx), glyph, color)
+                    }
+                };
+                best = match best {
+                    Some(current) if current.0 >= candidate.0 => Some(current),
+                    _ => Some(candidate),
+                };
+            }
+        }
+        best.map(|(_, glyph, color)| (glyph, color))
+    }
+
+    pub fn draw(&self, ctx: &mut Rltk) {
+        // later entries are drawn on top in the full map, so let them win here too
+        let renderables: HashMap<usize, &RenderData> =
+            self.renderables.iter().map(|r| (r.idx, r)).collect();
+        let (width, height) = Self::get_size(self.level, self.scale_x, self.scale_y);
+        for cell_y in 0..height {
+            for cell_x in 0..width {
+                let (glyph, fg) = self
+                    .get_cell(cell_x, cell_y, &renderables)
+                    .unwrap_or((rltk::to_cp437(' '), RGB::named(GREY)));
+                ctx.set(
+                    self.x + cell_x,
+                    self.y + cell_y,
+                    fg,
+                    RGB::named(BLACK),
+                    glyph,
+                );
+            }
+        }
+    }
+}
@@ -1,7 +1,12 @@
 use std::collections::HashSet;
 
-use crate::dungeon::{level::Level, level_utils, tile_type::TileType};
-use crate::screens::constants::MAP_WIDTH;
+use crate::dungeon::{
+    level::Level, level_utils, remembered_entity::RememberedEntity, tile_type::TileType,
+};
+use crate::screens::{
+    constants::MAP_WIDTH,
+    sprite_sheet::{get_sprite_for_glyph, get_sprite_for_tile, set_sprite, RenderMode},
+};
 use rltk::{Rltk, RGB};
 
 pub struct RenderData {
@@ -30,7 +35,7 @@ fn is_revealed_and_ledge(level: &Level, x: i32, y: i32) -> bool {
     }
 }
 
-pub fn get_wall_tile(level: &Level, x: i32, y: i32) -> u16 {
+pub fn get_wall_mask(level: &Level, x: i32, y: i32) -> u8 {
     let mut mask: u8 = 0;
     if is_revealed_and_wall_or_door(level, x, y - 1) {
         mask += 1;
@@ -44,7 +49,11 @@ pub fn get_wall_tile(level: &Level, x: i32, y: i32) -> u16 {
     if is_revealed_and_wall_or_door(level, x + 1, y) {
         mask += 8;
     }
-    match mask {
+    mask
+}
+
+pub fn get_wall_tile(level: &Level, x: i32, y: i32) -> u16 {
+    match get_wall_mask(level, x, y) {
         0 => 9,
         1 | 2 | 3 => 186,
         4 | 8 | 12 => 205,
@@ -61,7 +70,7 @@ pub fn get_wall_tile(level: &Level, x: i32, y: i32) -> u16 {
     }
 }
 
-fn get_ledge_tile(level: &Level, x: i32, y: i32) -> u16 {
+fn get_ledge_mask(level: &Level, x: i32, y: i32) -> u8 {
     let mut mask: u8 = 0;
     if is_revealed_and_ledge(level, x, y - 1) {
         mask += 1;
@@ -75,7 +84,11 @@ fn get_ledge_tile(level: &Level, x: i32, y: i32) -> u16 {
     if is_revealed_and_ledge(level, x + 1, y) {
         mask += 8;
     }
-    match mask {
+    mask
+}
+
+fn get_ledge_tile(level: &Level, x: i32, y: i32) -> u16 {
+    match get_ledge_mask(level, x, y) {
         0 => 9,
         1 | 2 | 3 => 179,
         4 | 8 | 12 => 196,
@@ -104,6 +117,7 @@ pub struct UIMap<'a> {
     renderables: &'a Vec<RenderData>,
     render_offset: (i32, i32),
     visible_tiles: &'a HashSet<usize>,
+    render_mode: RenderMode,
 }
 
 impl<'a> UIMap<'a> {
@@ -112,22 +126,65 @@ impl<'a> UIMap<'a> {
         renderables: &'a Vec<RenderData>,
         render_offset: (i32, i32),
         visible_tiles: &'a HashSet<usize>,
+        render_mode: RenderMode,
     ) -> Self {
         Self {
             level,
             renderables,
             render_offset,
             visible_tiles,
+            render_mode,
+        }
+    }
+
+    fn get_tile_sprite(&self, tile: &TileType, x: i32, y: i32) -> Option<u16> {
+        match self.render_mode {
+            RenderMode::Ascii => None,
+            RenderMode::Tileset => {
+                let mask = match tile {
+                    TileType::Wall => get_wall_mask(&self.level, x, y),
+                    TileType::Ledge => get_ledge_mask(&self.level, x, y),
+                    _ => 0,
+                };
+                Some(get_sprite_for_tile(tile, mask))
+            }
+        }
+    }
+
+    fn get_entity_sprite(&self, glyph: u16) -> Option<u16> {
+        match self.render_mode {
+            RenderMode::Ascii => None,
+            RenderMode::Tileset => get_sprite_for_glyph(glyph),
         }
     }
 
     pub fn draw(&mut self, ctx: &mut Rltk) {
+        // cells that already have a glyph on the text console, a sprite drawn
+        // there would be hidden underneath it
+        let mut text_cells: HashSet<usize> = HashSet::new();
         // this could be better, the level knows what ents are there and we can get the renderables from there
         // why bother collecting them beforehand?
         for (i, tile) in self.level.tiles.iter().enumerate() {
             if self.level.revealed_tiles[i] {
                 let x = i % MAP_WIDTH as usize;
                 let y = (i - (i % MAP_WIDTH as usize)) / MAP_WIDTH as usize;
+                let is_visible = self.visible_tiles.contains(&i);
+                if let Some(sprite) = self.get_tile_sprite(tile, x as i32, y as i32) {
+                    let tint = match self.level.get_branch_at_idx(i) {
+                        Some(_) if is_visible => RGB::named(rltk::CYAN),
+                        Some(_) => RGB::named(rltk::LIGHT_BLUE),
+                        None if is_visible => RGB::named(rltk::WHITE),
+                        None => RGB::named(rltk::GREY),
+                    };
+                    set_sprite(
+                        ctx,
+                        x as i32 - self.render_offset.0,
+                        y as i32 - self.render_offset.1,
+                        tint,
+                        sprite,
+                    );
+                    continue;
+                }
                 let character = match tile {
                     TileType::Floor | TileType::Door => rltk::to_cp437('.'),
                     TileType::Wall => get_wall_tile(&self.level, x as i32, y as i32),
@@ -138,9 +195,11 @@ impl<'a> UIMap<'a> {
                     TileType::WaterDeep => 176,
                     TileType::Ledge => get_ledge_tile(&self.level, x as i32, y as i32),
                 };
-                let foreground_color = match self.visible_tiles.contains(&i) {
-                    true => rltk::GREEN,
-                    false => rltk::WHITE,
+                let foreground_color = match self.level.get_branch_at_idx(i) {
+                    Some(_) if is_visible => rltk::CYAN,
+                    Some(_) => rltk::LIGHT_BLUE,
+                    None if is_visible => rltk::GREEN,
+                    None => rltk::WHITE,
                 };
                 ctx.set(
                     x as i32 - self.render_offset.0,
@@ -148,20 +207,66 @@ impl<'a> UIMap<'a> {
                     RGB::named(foreground_color),
                     get_bg_color(tile),
                     character,
-                )
+                );
+                text_cells.insert(i);
+            }
+        }
+        let mut remembered: Vec<&RememberedEntity> = self
+            .level
+            .remembered_entities
+            .iter()
+            .filter(|r| !self.visible_tiles.contains(&r.idx))
+            .collect();
+        remembered.sort_unstable_by(|a, b| b.layer.cmp(&a.layer));
+        for r in remembered.iter() {
+            let (x, y) = level_utils::idx_xy(self.level.width as u32, r.idx);
+            match self.get_entity_sprite(r.glyph) {
+                Some(sprite) => set_sprite(
+                    ctx,
+                    x - self.render_offset.0,
+                    y - self.render_offset.1,
+                    r.fg.to_greyscale(),
+                    sprite,
+                ),
+                None => {
+                    ctx.set(
+                        x - self.render_offset.0,
+                        y - self.render_offset.1,
+                        r.fg.to_greyscale(),
+                        RGB::named(rltk::BLACK),
+                        r.glyph,
+                    );
+                    text_cells.insert(r.idx);
+                }
             }
         }
         // since we're just going to reset the context, it seems a shame we did the above
         // `work
         for r in self.renderables.iter() {
             let (x, y) = level_utils::idx_xy(self.level.width as u32, r.idx);
-            ctx.set(
-                x - self.render_offset.0,
-                y - self.render_offset.1,
-                r.fg,
-                r.bg,
-                r.glyph,
-            );
+            let sprite = match text_cells.contains(&r.idx) {
+                true => None,
+                false => self.get_entity_sprite(r.glyph),
+            };
+            match sprite {
+                Some(sprite) => set_sprite(
+                    ctx,
+                    x - self.render_offset.0,
+                    y - self.render_offset.1,
+                    r.fg,
+                    sprite,
+                ),
+                None => {
+                    ctx.set(
+                        x - self.render_offset.0,
+                        y - self.render_offset.1,
+                        r.fg,
+                        r.bg,
+                        r.glyph,
+                    );
+                    text_cells.insert(r.idx);
+                }
+            }
         }
     }
 }
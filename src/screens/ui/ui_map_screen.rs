@@ -6,22 +6,24 @@ use super::{
     ui_mouse_pos::UIMousePos,
     ui_tooltip::{UIToolTip, UIToolTipPosition},
 };
-use crate::dungeon::level::Level;
-use crate::screens::constants::SCREEN_WIDTH;
+use crate::dungeon::{level::Level, level_id::LevelId};
+use crate::screens::{constants::SCREEN_WIDTH, sprite_sheet::RenderMode};
+use crate::services::game_log::LogEntry;
 use rltk::Rltk;
 
 pub struct UIMapScreen<'a, 'b> {
     mouse_x: i32,
     mouse_y: i32,
     tool_tip_lines: &'b Box<[&'a str]>,
-    messages: &'b Box<[&'a str]>,
-    depth: u8,
+    messages: &'b Box<[&'a LogEntry]>,
+    level_id: LevelId,
     hp: i32,
     max_hp: i32,
     level: &'a Level,
     renderables: &'a Vec<RenderData>,
     render_offset: (i32, i32),
-    visible_tiles: &'a HashSet<usize>
+    visible_tiles: &'a HashSet<usize>,
+    render_mode: RenderMode,
 }
 
 impl<'a, 'b> UIMapScreen<'a, 'b> {
@@ -29,33 +31,42 @@ impl<'a, 'b> UIMapScreen<'a, 'b> {
         mouse_x: i32,
         mouse_y: i32,
         tool_tip_lines: &'b Box<[&'a str]>,
-        messages: &'b Box<[&'a str]>,
-        depth: u8,
+        messages: &'b Box<[&'a LogEntry]>,
+        level_id: LevelId,
         hp: i32,
         max_hp: i32,
         level: &'a Level,
         renderables: &'a Vec<RenderData>,
         render_offset: (i32, i32),
-        visible_tiles: &'a HashSet<usize>
+        visible_tiles: &'a HashSet<usize>,
+        render_mode: RenderMode,
     ) -> Self {
         Self {
             mouse_x,
             mouse_y,
             tool_tip_lines,
             messages,
-            depth,
+            level_id,
             hp,
             max_hp,
             level,
             renderables,
             render_offset,
-            visible_tiles
+            visible_tiles,
+            render_mode,
         }
     }
 
     pub fn draw(&self, ctx: &mut Rltk) {
-        UIMap::new(self.level, self.renderables, self.render_offset, self.visible_tiles).draw(ctx);
-        UIHud::new(self.depth, self.hp, self.max_hp, self.messages).draw(ctx);
+        UIMap::new(
+            self.level,
+            self.renderables,
+            self.render_offset,
+            self.visible_tiles,
+            self.render_mode,
+        )
+        .draw(ctx);
+        UIHud::new(self.level_id, self.hp, self.max_hp, self.messages).draw(ctx);
         if !self.tool_tip_lines.is_empty() {
             let tool_tip_pos = match self.mouse_x > (SCREEN_WIDTH / 2) as i32 {
                 true => UIToolTipPosition::Left,
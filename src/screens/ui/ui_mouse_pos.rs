@@ -1,3 +1,4 @@
+use crate::screens::sprite_sheet::highlight_cell;
 use rltk::{Rltk, MAGENTA, RGB};
 
 pub struct UIMousePos {
@@ -11,6 +12,6 @@ impl UIMousePos {
     }
 
     pub fn draw(&self, ctx: &mut Rltk) {
-        ctx.set_bg(self.x, self.y, RGB::named(MAGENTA));
+        highlight_cell(ctx, self.x, self.y, RGB::named(MAGENTA));
     }
 }
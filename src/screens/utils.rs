@@ -1,8 +1,10 @@
 use super::{
     constants::{MAP_HEIGHT, MAP_WIDTH},
     ui::ui_map::RenderData,
+    viewport::Viewport,
 };
 use crate::components::{Hidden, Hiding, OnFire, Position, Renderable, Viewshed};
+use crate::dungeon::{dungeon::Dungeon, level_utils};
 use rltk::{GREY, ORANGE, RGB};
 use specs::{Entity, Join, World, WorldExt};
 
@@ -59,6 +61,16 @@ pub fn get_render_data(world: &World) -> Vec<RenderData> {
     return render_data;
 }
 
+pub fn get_player_view_center(world: &World) -> (i32, i32) {
+    let player_ent = world.fetch::<Entity>();
+    let positions = world.read_storage::<Position>();
+    let player_position = positions.get(*player_ent).unwrap();
+    let dungeon = world.fetch::<Dungeon>();
+    let level = dungeon.get_level(player_position.level).unwrap();
+    let (x, y) = level_utils::idx_xy(level.width as u32, player_position.idx);
+    world.fetch_mut::<Viewport>().follow(x, y)
+}
+
 pub fn get_render_offset(center_x: i32, center_y: i32) -> (i32, i32) {
     let offset_x = center_x - MAP_WIDTH as i32 / 2;
     let offset_y = center_y - MAP_HEIGHT as i32 / 2;
@@ -0,0 +1,44 @@
+use super::constants::{MAP_HEIGHT, MAP_WIDTH};
+
+pub const DEFAULT_VIEWPORT_MARGIN: i32 = 12;
+
+pub struct Viewport {
+    pub center: Option<(i32, i32)>,
+    pub margin: i32,
+    pub show_minimap: bool,
+}
+
+impl Viewport {
+    pub fn new(margin: i32, show_minimap: bool) -> Self {
+        Self {
+            center: None,
+            margin,
+            show_minimap,
+        }
+    }
+
+    pub fn reset(&mut self) {
+        self.center = None;
+    }
+
+    fn follow_axis(center: i32, target: i32, half_size: i32, margin: i32) -> i32 {
+        let margin = margin.max(0).min(half_size - 1);
+        if target < center - half_size + margin {
+            target + half_size - margin
+        } else if target > center + half_size - 1 - margin {
+            target - half_size + 1 + margin
+        } else {
+            center
+        }
+    }
+
+    pub fn follow(&mut self, x: i32, y: i32) -> (i32, i32) {
+        let (center_x, center_y) = self.center.unwrap_or((x, y));
+        let center = (
+            Self::follow_axis(center_x, x, MAP_WIDTH as i32 / 2, self.margin),
+            Self::follow_axis(center_y, y, MAP_HEIGHT as i32 / 2, self.margin),
+        );
+        self.center = Some(center);
+        center
+    }
+}
@@ -1,3 +1,4 @@
+use crate::dungeon::level_id::LevelId;
 use rltk::RGB;
 
 pub struct BloodSpawnerRequest {
@@ -5,7 +6,7 @@ pub struct BloodSpawnerRequest {
     pub fg: RGB,
     pub bg: RGB,
     pub glyph: u16,
-    pub level: u8,
+    pub level: LevelId,
 }
 
 pub struct BloodSpawner {
@@ -19,7 +20,7 @@ impl BloodSpawner {
         }
     }
 
-    pub fn request(&mut self, idx: usize, fg: RGB, bg: RGB, glyph: u16, level: u8) {
+    pub fn request(&mut self, idx: usize, fg: RGB, bg: RGB, glyph: u16, level: LevelId) {
         self.requests.push(BloodSpawnerRequest {
             idx,
             fg,
@@ -1,15 +1,19 @@
+use crate::dungeon::level_id::LevelId;
 use rltk::{to_cp437, BLACK, DARK_RED, RGB};
 
 use crate::entity_set::EntitySet;
 
+const GOBLIN_CORPSE_NUTRITION: i32 = 200;
+
 pub struct CorpseSpawnerRequest {
     pub idx: usize,
     pub fg: RGB,
     pub bg: RGB,
     pub glyph: u16,
-    pub level: u8,
+    pub level: LevelId,
     pub name: String,
     pub items: EntitySet,
+    pub nutrition: Option<i32>,
 }
 
 pub struct CorpseSpawner {
@@ -29,9 +33,10 @@ impl CorpseSpawner {
         fg: RGB,
         bg: RGB,
         glyph: u16,
-        level: u8,
+        level: LevelId,
         name: String,
         items: EntitySet,
+        nutrition: Option<i32>,
     ) {
         self.requests.push(CorpseSpawnerRequest {
             idx,
@@ -41,13 +46,14 @@ impl CorpseSpawner {
             level,
             name,
             items,
+            nutrition,
         });
     }
 
     pub fn request_goblin_corpse(
         &mut self,
         idx: usize,
-        level: u8,
+        level: LevelId,
         cause_of_death: String,
         items: EntitySet,
     ) {
@@ -59,6 +65,7 @@ impl CorpseSpawner {
             level,
             format!("goblin corpse, {}", cause_of_death),
             items,
+            Some(GOBLIN_CORPSE_NUTRITION),
         );
     }
 }
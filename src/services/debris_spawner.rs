@@ -1,3 +1,4 @@
+use crate::dungeon::level_id::LevelId;
 use rltk::{BLACK, DARK_GRAY, RGB, to_cp437};
 
 pub struct DebrisSpawnerRequest {
@@ -5,7 +6,7 @@ pub struct DebrisSpawnerRequest {
     pub fg: RGB,
     pub bg: RGB,
     pub glyph: u16,
-    pub level: u8,
+    pub level: LevelId,
     pub name: String,
     pub flammable: bool
 }
@@ -21,7 +22,7 @@ impl DebrisSpawner {
         }
     }
 
-    pub fn request(&mut self, idx: usize, fg: RGB, bg: RGB, glyph: u16, level: u8, name: String, flammable: bool) {
+    pub fn request(&mut self, idx: usize, fg: RGB, bg: RGB, glyph: u16, level: LevelId, name: String, flammable: bool) {
         self.requests.push(DebrisSpawnerRequest {
             idx,
             fg,
@@ -33,7 +34,7 @@ impl DebrisSpawner {
         })
     }
 
-    pub fn request_burnt_debris(&mut self, idx: usize, level: u8) {
+    pub fn request_burnt_debris(&mut self, idx: usize, level: LevelId) {
         self.request(
             idx,
             RGB::named(BLACK),
@@ -1,9 +1,159 @@
+use rltk::{LIGHT_BLUE, ORANGE, WHITE};
+use serde::{Deserialize, Serialize};
+use std::collections::VecDeque;
+
+pub const MAX_LOG_ENTRIES: usize = 500;
+
+#[derive(PartialEq, Clone, Copy, Debug)]
+pub enum LogCategory {
+    Combat,
+    Discovery,
+    System,
+}
+
+impl LogCategory {
+    pub fn categories() -> Box<[Self]> {
+        Box::new([Self::Combat, Self::Discovery, Self::System])
+    }
+
+    pub fn get_next_filter(filter: Option<Self>) -> Option<Self> {
+        let categories = Self::categories();
+        match filter {
+            Some(category) => {
+                let idx = categories.iter().position(|c| *c == category).unwrap();
+                categories.get(idx + 1).copied()
+            }
+            None => categories.first().copied(),
+        }
+    }
+
+    pub fn get_previous_filter(filter: Option<Self>) -> Option<Self> {
+        let categories = Self::categories();
+        match filter {
+            Some(category) => {
+                let idx = categories.iter().position(|c| *c == category).unwrap();
+                match idx {
+                    0 => None,
+                    _ => categories.get(idx - 1).copied(),
+                }
+            }
+            None => categories.last().copied(),
+        }
+    }
+
+    pub fn get_name(&self) -> &'static str {
+        match self {
+            Self::Combat => "Combat",
+            Self::Discovery => "Discovery",
+            Self::System => "System",
+        }
+    }
+
+    pub fn get_color(&self) -> (u8, u8, u8) {
+        match self {
+            Self::Combat => ORANGE,
+            Self::Discovery => LIGHT_BLUE,
+            Self::System => WHITE,
+        }
+    }
+}
+
+// controls which entries show up in the HUD, the full message log always has everything
+#[derive(PartialEq, Clone, Copy, Debug, Serialize, Deserialize)]
+pub enum MessageVerbosity {
+    All,
+    NoSystem,
+    CombatOnly,
+}
+
+impl MessageVerbosity {
+    pub fn get_name(&self) -> &'static str {
+        match self {
+            Self::All => "All messages",
+            Self::NoSystem => "Hide system messages",
+            Self::CombatOnly => "Combat only",
+        }
+    }
+
+    pub fn get_next(&self) -> Self {
+        match self {
+            Self::All => Self::NoSystem,
+            Self::NoSystem => Self::CombatOnly,
+            Self::CombatOnly => Self::All,
+        }
+    }
+
+    pub fn shows(&self, category: LogCategory) -> bool {
+        match self {
+            Self::All => true,
+            Self::NoSystem => category != LogCategory::System,
+            Self::CombatOnly => category == LogCategory::Combat,
+        }
+    }
+}
+
+pub struct LogEntry {
+    pub turn: u32,
+    pub category: LogCategory,
+    pub color: (u8, u8, u8),
+    pub message: String,
+}
+
 pub struct GameLog {
-    pub entries: Vec<String>,
+    pub entries: VecDeque<LogEntry>,
+    pub turn: u32,
 }
 
 impl GameLog {
-    pub fn add(&mut self, message: String) {
-        self.entries.insert(0, message);
+    pub fn new(intro: &str) -> Self {
+        let mut log = Self {
+            entries: VecDeque::new(),
+            turn: 0,
+        };
+        log.add_system(intro.to_string());
+        log
+    }
+
+    pub fn add(&mut self, category: LogCategory, message: String) {
+        self.entries.push_front(LogEntry {
+            turn: self.turn,
+            category,
+            color: category.get_color(),
+            message,
+        });
+        self.entries.truncate(MAX_LOG_ENTRIES);
+    }
+
+    pub fn add_combat(&mut self, message: String) {
+        self.add(LogCategory::Combat, message);
+    }
+
+    pub fn add_discovery(&mut self, message: String) {
+        self.add(LogCategory::Discovery, message);
+    }
+
+    pub fn add_system(&mut self, message: String) {
+        self.add(LogCategory::System, message);
+    }
+
+    pub fn advance_turn(&mut self) {
+        self.turn += 1;
+    }
+
+    pub fn get_filtered_entries(&self, filter: Option<LogCategory>) -> Vec<&LogEntry> {
+        self.entries
+            .iter()
+            .filter(|entry| match filter {
+                Some(category) => entry.category == category,
+                None => true,
+            })
+            .collect()
+    }
+
+    pub fn get_entries_for_verbosity(&self, verbosity: MessageVerbosity) -> Vec<&LogEntry> {
+        self.entries
+            .iter()
+            .filter(|entry| verbosity.shows(entry.category))
+            .collect()
     }
 }
@@ -0,0 +1,123 @@
+use crate::components::identifiable::IdentifiableKind;
+use rltk::{RandomNumberGenerator, RGB};
+use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, HashSet};
+
+const POTION_APPEARANCES: [(&str, (u8, u8, u8)); 8] = [
+    ("Murky", rltk::DARK_OLIVE_GREEN),
+    ("Bubbling", rltk::LIGHT_GREEN),
+    ("Cloudy Blue", rltk::LIGHT_BLUE),
+    ("Crimson", rltk::FIREBRICK),
+    ("Golden", rltk::GOLD),
+    ("Smoky", rltk::SLATE_GREY),
+    ("Violet", rltk::VIOLET),
+    ("Pale Pink", rltk::PINK),
+];
+const SCROLL_APPEARANCES: [&str; 8] = [
+    "KORVA NESH",
+    "ULTH ABRAXIS",
+    "MORDA QUIL",
+    "ZYNTH OPAL",
+    "GRUNDE VAL",
+    "ISSA TORN",
+    "BLEN FARRO",
+    "VOX ERRATA",
+];
+// every scroll looks alike until it is read
+const SCROLL_COLOUR: (u8, u8, u8) = rltk::WHEAT;
+
+fn get_appearance_name(kind: IdentifiableKind, appearance: &str) -> String {
+    match kind {
+        IdentifiableKind::Potion => format!("{} Potion", appearance),
+        IdentifiableKind::Scroll => format!("Scroll labelled {}", appearance),
+    }
+}
+
+fn get_appearance_names(kind: IdentifiableKind) -> Vec<String> {
+    match kind {
+        IdentifiableKind::Potion => POTION_APPEARANCES
+            .iter()
+            .map(|(appearance, _)| get_appearance_name(kind, appearance))
+            .collect(),
+        IdentifiableKind::Scroll => SCROLL_APPEARANCES
+            .iter()
+            .map(|appearance| get_appearance_name(kind, appearance))
+            .collect(),
+    }
+}
+
+pub fn get_appearance_colour(kind: IdentifiableKind, appearance_name: &str) -> RGB {
+    let colour = match kind {
+        IdentifiableKind::Potion => POTION_APPEARANCES
+            .iter()
+            .find(|(appearance, _)| get_appearance_name(kind, appearance) == appearance_name)
+            .map_or(rltk::WHITE, |(_, colour)| *colour),
+        IdentifiableKind::Scroll => SCROLL_COLOUR,
+    };
+    RGB::named(colour)
+}
+
+pub fn get_unidentified_description(kind: IdentifiableKind) -> &'static str {
+    match kind {
+        IdentifiableKind::Potion => {
+            "A stoppered flask of something unknown. Drinking it is one way to find out what it does."
+        }
+        IdentifiableKind::Scroll => {
+            "A scroll covered in words you can't make sense of. Reading it is one way to find out what it does."
+        }
+    }
+}
+
+// what unidentified potions and scrolls look like this run and which of them the player knows,
+// saved with the game so appearances stay the same between sessions
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct Identification {
+    appearances: HashMap<String, String>,
+    identified: HashSet<String>,
+}
+
+impl Identification {
+    pub fn new() -> Self {
+        Self {
+            appearances: HashMap::new(),
+            identified: HashSet::new(),
+        }
+    }
+
+    pub fn is_identified(&self, name: &str) -> bool {
+        self.identified.contains(name)
+    }
+
+    // true if the item wasn't already identified
+    pub fn identify(&mut self, name: &str) -> bool {
+        self.identified.insert(name.to_string())
+    }
+
+    // appearances are handed out the first time an item is seen, never sharing one while
+    // there are unused ones left
+    pub fn get_appearance(
+        &mut self,
+        kind: IdentifiableKind,
+        name: &str,
+        rng: &mut RandomNumberGenerator,
+    ) -> String {
+        if let Some(appearance) = self.appearances.get(name) {
+            return appearance.clone();
+        }
+        let all_appearances = get_appearance_names(kind);
+        let unused: Vec<String> = all_appearances
+            .iter()
+            .filter(|appearance| !self.appearances.values().any(|used| used == *appearance))
+            .cloned()
+            .collect();
+        let appearance = match unused.is_empty() {
+            true => rng.random_slice_entry(&all_appearances),
+            false => rng.random_slice_entry(&unused),
+        }
+        .cloned()
+        .unwrap();
+        self.appearances
+            .insert(name.to_string(), appearance.clone());
+        appearance
+    }
+}
@@ -1,8 +1,9 @@
+use crate::dungeon::level_id::LevelId;
 use crate::types::ItemType;
 
 pub struct ItemSpawnerRequest {
     pub idx: usize,
-    pub level: u8,
+    pub level: LevelId,
     pub item_type: ItemType,
 }
 
@@ -17,7 +18,7 @@ impl ItemSpawner {
         }
     }
 
-    pub fn request(&mut self, idx: usize, level: u8, item_type: ItemType) {
+    pub fn request(&mut self, idx: usize, level: LevelId, item_type: ItemType) {
         self.requests.push(ItemSpawnerRequest {
             idx,
             level,
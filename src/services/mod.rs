@@ -1,15 +1,19 @@
 pub mod blood_spawner;
 pub mod debris_spawner;
 pub mod game_log;
+pub mod identification;
 pub mod item_spawner;
 pub mod particle_effect_spawner;
+pub mod sound_effect_queue;
 pub mod trap_spawner;
 pub mod corpse_spawner;
 
 pub use blood_spawner::BloodSpawner;
 pub use debris_spawner::DebrisSpawner;
 pub use game_log::GameLog;
+pub use identification::Identification;
 pub use item_spawner::ItemSpawner;
 pub use particle_effect_spawner::ParticleEffectSpawner;
+pub use sound_effect_queue::SoundEffectQueue;
 pub use trap_spawner::TrapSpawner;
 pub use corpse_spawner::CorpseSpawner;
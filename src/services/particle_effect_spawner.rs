@@ -1,3 +1,4 @@
+use crate::dungeon::level_id::LevelId;
 use rltk::{RGB, to_cp437, ORANGE, BLACK, BLUE};
 
 pub struct ParticleEffectSpawnerRequest {
@@ -6,7 +7,7 @@ pub struct ParticleEffectSpawnerRequest {
     pub bg: RGB,
     pub glyph: u16,
     pub lifetime: f32,
-    pub level: u8,
+    pub level: LevelId,
 }
 
 pub struct ParticleEffectSpawner {
@@ -27,7 +28,7 @@ impl ParticleEffectSpawner {
         bg: RGB,
         glyph: u16,
         lifetime: f32,
-        level: u8,
+        level: LevelId,
     ) {
         self.requests.push(ParticleEffectSpawnerRequest {
             idx,
@@ -39,7 +40,7 @@ impl ParticleEffectSpawner {
         })
     }
 
-    pub fn request_attack_particle(&mut self, idx: usize, level: u8) {
+    pub fn request_attack_particle(&mut self, idx: usize, level: LevelId) {
         self.request(
             idx,
             RGB::named(ORANGE),
@@ -50,7 +51,7 @@ impl ParticleEffectSpawner {
           );
     }
 
-    pub fn request_search_particle(&mut self, idx: usize, level: u8) {
+    pub fn request_search_particle(&mut self, idx: usize, level: LevelId) {
         self.request(
             idx,
             RGB::named(BLUE),
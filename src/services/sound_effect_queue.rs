@@ -0,0 +1,25 @@
+use crate::audio::SoundEffect;
+use crate::dungeon::level_id::LevelId;
+
+pub struct SoundEffectRequest {
+    pub effect: SoundEffect,
+    pub idx: usize,
+    pub level: LevelId,
+}
+
+pub struct SoundEffectQueue {
+    pub requests: Vec<SoundEffectRequest>,
+}
+
+impl SoundEffectQueue {
+    pub fn new() -> Self {
+        SoundEffectQueue {
+            requests: Vec::new(),
+        }
+    }
+
+    pub fn request(&mut self, effect: SoundEffect, idx: usize, level: LevelId) {
+        self.requests
+            .push(SoundEffectRequest { effect, idx, level })
+    }
+}
@@ -1,9 +1,10 @@
+use crate::dungeon::level_id::LevelId;
 use crate::types::TrapType;
 use specs::Entity;
 
 pub struct TrapSpawnerRequest {
     pub idx: usize,
-    pub level: u8,
+    pub level: LevelId,
     pub set_by: Entity,
     pub trap_type: TrapType,
 }
@@ -19,7 +20,7 @@ impl TrapSpawner {
         }
     }
 
-    pub fn request(&mut self, idx: usize, level: u8, set_by: Entity, trap_type: TrapType) {
+    pub fn request(&mut self, idx: usize, level: LevelId, set_by: Entity, trap_type: TrapType) {
         self.requests.push(TrapSpawnerRequest {
             idx,
             level,
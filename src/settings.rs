@@ -1,5 +1,13 @@
+#[cfg(not(target_arch = "wasm32"))]
+use crate::paths;
 use crate::{
-    control::ControlMap,
+    auto_explore::ExploreInterrupts,
+    control::{Control, ControlMap},
+    screens::{
+        sprite_sheet::{RenderMode, TextFont},
+        viewport::DEFAULT_VIEWPORT_MARGIN,
+    },
+    services::game_log::MessageVerbosity,
     user_actions::{
         InteractionTargetingAction, MapAction, MenuAction, StaticAction, TargetingAction,
     },
@@ -9,14 +17,14 @@ use ron::ser::{to_string_pretty, PrettyConfig};
 use serde::{Deserialize, Serialize};
 #[cfg(not(target_arch = "wasm32"))]
 use std::{
-    env,
-    fs::{DirBuilder, File},
+    fs::File,
     io::{Read, Write},
 };
 #[cfg(target_arch = "wasm32")]
 use web_sys;
 
 const DEFAULT_CONTROLS_STRING: &str = include_str!("./default_settings/controls.ron");
+pub const MACRO_SLOTS: usize = 3;
 
 #[derive(Serialize, Deserialize)]
 pub struct ControlScheme {
@@ -26,23 +34,46 @@ pub struct ControlScheme {
     pub interaction: ControlMap<InteractionTargetingAction>,
     pub static_screen: ControlMap<StaticAction>,
     pub targeting: ControlMap<TargetingAction>,
+    // recorded map actions, played back with the PlayMacro actions
+    #[serde(default)]
+    pub macros: [Vec<MapAction>; MACRO_SLOTS],
 }
 
-#[cfg(all(target_os = "windows", not(target_arch = "wasm32")))]
-fn get_settings_dir() -> String {
-    let home_dir = env::var("APPDATA").unwrap();
-    format!("{}/Apprentice", home_dir)
-}
+const CONTROLS_FILE_NAME: &str = "key-bindings.ron";
+const PREFERENCES_FILE_NAME: &str = "preferences.ron";
 
 #[cfg(not(target_arch = "wasm32"))]
-fn get_settings_dir() -> String {
-    let home_dir = env::var("HOME").unwrap();
-    format!("{}/Library/Application Support/apprentice", home_dir)
+fn write_settings_file(file_name: &str, contents: &str) {
+    paths::create_config_dir().expect("failed to create settings dir");
+    let mut file = File::create(paths::get_config_file_path(file_name))
+        .expect("failed to create settings file");
+    file.write_all(contents.as_bytes())
+        .expect("failed to populate settings file");
+}
+
+#[cfg(target_arch = "wasm32")]
+fn write_settings_file(file_name: &str, contents: &str) {
+    let window = web_sys::window().expect("no global `window` exists");
+    let storage = window.local_storage().unwrap().expect("no local storage");
+    storage
+        .set_item(file_name, contents)
+        .expect("could not write to local storage");
 }
 
 #[cfg(not(target_arch = "wasm32"))]
-fn get_settings_filepath() -> String {
-    format!("{}/key-bindings.ron", get_settings_dir())
+fn read_settings_file(file_name: &str) -> Option<String> {
+    let path = paths::find_existing_file(paths::get_config_file_path(file_name), file_name)?;
+    let mut settings_file = File::open(path).ok()?;
+    let mut settings = String::new();
+    settings_file.read_to_string(&mut settings).ok()?;
+    Some(settings)
+}
+
+#[cfg(target_arch = "wasm32")]
+fn read_settings_file(file_name: &str) -> Option<String> {
+    let window = web_sys::window().expect("no global `window` exists");
+    let storage = window.local_storage().unwrap().expect("no local storage");
+    storage.get_item(file_name).ok().flatten()
 }
 
 impl ControlScheme {
@@ -64,62 +95,121 @@ impl ControlScheme {
         }
     }
 
-    #[cfg(not(target_arch = "wasm32"))]
-    pub fn save(&self) {
-        DirBuilder::new()
-            .recursive(true)
-            .create(get_settings_dir())
-            .expect("failed to create settings dir");
-        let mut file =
-            File::create(get_settings_filepath()).expect("failed to create settings file");
-        file.write_all(self.as_ron_string().as_bytes())
-            .expect("failed to populate settings file");
+    // describes everything else the control is already bound to, only map actions can be rebound
+    // but a binding shared with a menu or targeting action would do both in those screens
+    pub fn get_conflicts(&self, control: &Control, action: &MapAction) -> Vec<String> {
+        let map = self
+            .map
+            .get_value(control)
+            .filter(|bound| *bound != action)
+            .map(|bound| format!("{} (Map)", bound));
+        let menu = self
+            .menu
+            .get_value(control)
+            .map(|bound| format!("{} (Menu)", bound));
+        let targeting = self
+            .targeting
+            .get_value(control)
+            .map(|bound| format!("{} (Targeting)", bound));
+        map.into_iter().chain(menu).chain(targeting).collect()
     }
 
-    #[cfg(target_arch = "wasm32")]
     pub fn save(&self) {
-        let window = web_sys::window().expect("no global `window` exists");
-        let storage = window.local_storage().unwrap().expect("no local storage");
-        storage
-            .set_item("key-bindings.ron", self.as_ron_string().as_str())
-            .expect("could not write to local storage");
+        write_settings_file(CONTROLS_FILE_NAME, self.as_ron_string().as_str());
     }
 
-    #[cfg(not(target_arch = "wasm32"))]
     pub fn load() -> Self {
-        if let Ok(mut settings_file) = File::open(get_settings_filepath()) {
-            let mut settings = String::new();
-            if let Ok(_) = settings_file.read_to_string(&mut settings) {
-                return Self::from_ron_string(settings.as_str());
-            }
+        match read_settings_file(CONTROLS_FILE_NAME) {
+            Some(controls_string) => Self::from_ron_string(controls_string.as_str()),
+            None => Self::default(),
         }
-        Self::default()
     }
+}
 
-    #[cfg(target_arch = "wasm32")]
-    pub fn load() -> Self {
-        let window = web_sys::window().expect("no global `window` exists");
-        let storage = window.local_storage().unwrap().expect("no local storage");
-        match storage.get_item("key-bindings.ron") {
-            Ok(r) => match r {
-                Some(controls_string) => Self::from_ron_string(controls_string.as_str()),
-                _ => Self::default(),
-            },
-            _ => Self::default(),
+#[derive(Serialize, Deserialize)]
+#[serde(default)]
+pub struct Preferences {
+    pub music_volume: u8,
+    pub music_muted: bool,
+    pub sound_effect_volume: u8,
+    pub sound_effects_muted: bool,
+    pub fullscreen: bool,
+    pub render_mode: RenderMode,
+    pub text_font: TextFont,
+    pub scanlines: bool,
+    pub show_minimap: bool,
+    // how close the player can get to the edge of the view before it scrolls
+    pub viewport_margin: i32,
+    pub message_verbosity: MessageVerbosity,
+    pub explore_interrupts: ExploreInterrupts,
+}
+
+impl Default for Preferences {
+    fn default() -> Self {
+        Self {
+            music_volume: 100,
+            music_muted: false,
+            sound_effect_volume: 100,
+            sound_effects_muted: false,
+            fullscreen: false,
+            render_mode: RenderMode::Ascii,
+            text_font: TextFont::Terminal8x8,
+            scanlines: false,
+            show_minimap: false,
+            viewport_margin: DEFAULT_VIEWPORT_MARGIN,
+            message_verbosity: MessageVerbosity::All,
+            explore_interrupts: ExploreInterrupts::default(),
+        }
+    }
+}
+
+impl Preferences {
+    pub fn get_music_volume(&self) -> f32 {
+        match self.music_muted {
+            true => 0.0,
+            false => self.music_volume as f32 / 100.0,
+        }
+    }
+
+    pub fn get_sound_effect_volume(&self) -> f32 {
+        match self.sound_effects_muted {
+            true => 0.0,
+            false => self.sound_effect_volume as f32 / 100.0,
         }
     }
+
+    fn as_ron_string(&self) -> String {
+        let my_config = PrettyConfig::new()
+            .with_depth_limit(2)
+            .with_indentor("\t".to_owned());
+        to_string_pretty(self, my_config).unwrap()
+    }
+
+    pub fn save(&self) {
+        write_settings_file(PREFERENCES_FILE_NAME, self.as_ron_string().as_str());
+    }
+
+    pub fn load() -> Self {
+        read_settings_file(PREFERENCES_FILE_NAME)
+            .and_then(|preferences| from_str::<Self>(preferences.as_str()).ok())
+            .unwrap_or_default()
+    }
 }
+
 pub struct Settings {
     pub control_scheme: ControlScheme,
+    pub preferences: Preferences,
 }
 
 impl Settings {
     pub fn load() -> Self {
         Settings {
             control_scheme: ControlScheme::load(),
+            preferences: Preferences::load(),
         }
     }
     pub fn save(&self) {
         self.control_scheme.save();
+        self.preferences.save();
     }
 }
@@ -1,14 +1,17 @@
 use crate::components::{
     causes_damage::DamageType, door::DoorState, equipable::EquipmentPositions,
-    monster::MonsterSpecies, Armable, DamageHistory, Disarmable, Door, Inventory, Lightable,
+    hunger::STARTING_NUTRITION, identifiable::IdentifiableKind, monster::MonsterSpecies, Armable,
+    Bulk, Capacity, Cookable, CookingSpot, DamageHistory, Disarmable, Door, Edible, Hunger,
+    Identifiable, Identifies, Inventory, Lightable, Regeneration,
 };
 use crate::components::{
     AreaOfEffect, BlocksTile, CausesDamage, CausesFire, CausesLight, CombatStats, Paralyze,
     Consumable, Container, Dousable, EntryTrigger, Equipable, Equipment, Flammable, Furniture,
     Grabbable, Hidden, HidingSpot, Info, Item, Memory, Monster, Name, Objective, Player, Position,
-    ProvidesHealing, Ranged, Renderable, Saveable, SingleActivation, Trap, Viewshed,
+    ProvidesHealing, Ranged, Renderable, Saveable, SingleActivation, Stackable, Trap, Viewshed,
 };
 use crate::dungeon::{
+    branch::Branch,
     constants::MAP_HEIGHT,
     level::Level,
     level_utils,
@@ -18,6 +21,7 @@ use crate::dungeon::{
     tile_type::TileType,
 };
 use crate::entity_set::EntitySet;
+use crate::inventory;
 use crate::types::{trap_type, TrapType};
 use crate::utils;
 use rltk::{to_cp437, RandomNumberGenerator, RGB};
@@ -36,6 +40,17 @@ pub const MAX_GOBLIN_GROUPS_PER_LEVEL: i32 = 4;
 pub const MIN_GOBLINS_PER_GROUP: i32 = 3;
 pub const MAX_GOBLINS_PER_GROUP: i32 = 6;
 pub const MAX_GOBLIN_SPACING: i32 = 4;
+// out of REGENERATION_THRESHOLD per turn, so the player heals a point every 10 turns
+pub const PLAYER_REGENERATION_RATE: u32 = 10;
+pub const MONSTER_REGENERATION_RATE: u32 = 5;
+pub const PLAYER_MAX_WEIGHT: u32 = 40;
+pub const PLAYER_MAX_VOLUME: u32 = 24;
+pub const BREAD_NUTRITION: i32 = 400;
+// raw meat is barely worth eating until it has been cooked on a lit stove
+pub const RAW_MEAT_NUTRITION: i32 = 150;
+pub const COOKED_MEAT_NUTRITION: i32 = 500;
+pub const MIN_SCATTERED_ITEMS_PER_LEVEL: i32 = 4;
+pub const MAX_SCATTERED_ITEMS_PER_LEVEL: i32 = 10;
 
 fn get_possible_spawn_points_in_level(level: &Level) -> Vec<usize> {
     level
@@ -81,7 +96,7 @@ fn create_marked_entity_with_position<'a>(
 ) -> EntityBuilder<'a> {
     create_marked_entity(world).with(Position {
         idx: position_idx,
-        level: level.depth,
+        level: level.id,
     })
 }
 
@@ -113,6 +128,10 @@ fn make_entity_sword<'a>(builder: EntityBuilder<'a>) -> EntityBuilder<'a> {
         .with(Name {
             name: "Sword".to_string(),
         })
+        .with(Bulk {
+            weight: 5,
+            volume: 4,
+        })
         .with(Info {
             description: String::from("A Sword, a sharp pointy item for poking, stabbing, slashing etc. It's polished blade gleams in the darkness. Your only friend in the infinite city.")
         })
@@ -129,6 +148,10 @@ fn make_entity_club<'a>(builder: EntityBuilder<'a>) -> EntityBuilder<'a> {
         .with(Name {
             name: "Club".to_string(),
         })
+        .with(Bulk {
+            weight: 4,
+            volume: 4,
+        })
         .with(Renderable {
             glyph: to_cp437('/'),
             fg: RGB::named(rltk::BROWN3),
@@ -140,6 +163,10 @@ fn make_entity_club<'a>(builder: EntityBuilder<'a>) -> EntityBuilder<'a> {
 fn make_entity_torch<'a>(builder: EntityBuilder<'a>, lit: bool) -> EntityBuilder<'a> {
     builder
         .with(Item {})
+        .with(Bulk {
+            weight: 2,
+            volume: 2,
+        })
         .with(Equipable {
             positions: Box::new([
                 EquipmentPositions::DominantHand,
@@ -205,6 +232,13 @@ pub fn spawn_player(world: &mut World, idx: usize, level: &Level) -> Entity {
             power: 2,
             defense: 0,
         })
+        .with(Regeneration {
+            rate: PLAYER_REGENERATION_RATE,
+            progress: 0,
+        })
+        .with(Hunger {
+            nutrition: STARTING_NUTRITION,
+        })
         .with(Equipment {
             dominant_hand: Some(sword),
             off_hand: Some(torch),
@@ -212,6 +246,10 @@ pub fn spawn_player(world: &mut World, idx: usize, level: &Level) -> Entity {
         .with(Inventory {
             items: EntitySet::new(),
         })
+        .with(Capacity {
+            max_weight: PLAYER_MAX_WEIGHT,
+            max_volume: PLAYER_MAX_VOLUME,
+        })
         .with(DamageHistory {
             events: HashSet::new(),
         })
@@ -252,6 +290,10 @@ pub fn spawn_monster<S: ToString>(
             defense: 0,
             power: 1,
         })
+        .with(Regeneration {
+            rate: MONSTER_REGENERATION_RATE,
+            progress: 0,
+        })
         .with(Equipment {
             dominant_hand: Some(club),
             off_hand: Some(torch),
@@ -282,6 +324,10 @@ fn spawn_objective(world: &mut World, idx: usize, level: &Level) -> Entity {
             layer: 1,
         })
         .with(Item {})
+        .with(Bulk {
+            weight: 1,
+            volume: 1,
+        })
         .with(Objective {})
         .build()
 }
@@ -309,6 +355,17 @@ fn make_entity_health_potion<'a>(builder: EntityBuilder<'a>) -> EntityBuilder<'a
             layer: 1,
         })
         .with(Item {})
+        .with(Bulk {
+            weight: 1,
+            volume: 1,
+        })
+        .with(Stackable { quantity: 1 })
+        .with(Identifiable {
+            kind: IdentifiableKind::Potion,
+            name: "Health Potion".to_string(),
+            description: "A potion that closes wounds and restores some health when drunk."
+                .to_string(),
+        })
         .with(Consumable {})
         .with(ProvidesHealing { amount: 8 })
 }
@@ -321,6 +378,70 @@ fn spawn_health_potion(world: &mut World) -> Entity {
     make_entity_health_potion(create_marked_entity(world)).build()
 }
 
+fn make_entity_bread<'a>(builder: EntityBuilder<'a>) -> EntityBuilder<'a> {
+    builder
+        .with(Name {
+            name: "Bread".to_string(),
+        })
+        .with(Renderable {
+            glyph: to_cp437('%'),
+            fg: RGB::named(rltk::WHEAT),
+            bg: RGB::named(rltk::BLACK),
+            layer: 1,
+        })
+        .with(Item {})
+        .with(Bulk {
+            weight: 1,
+            volume: 1,
+        })
+        .with(Stackable { quantity: 1 })
+        .with(Edible {
+            nutrition: BREAD_NUTRITION,
+        })
+}
+
+fn spawn_bread_with_position(world: &mut World, idx: usize, level: &Level) -> Entity {
+    make_entity_bread(create_marked_entity_with_position(world, idx, level)).build()
+}
+
+fn spawn_bread(world: &mut World) -> Entity {
+    make_entity_bread(create_marked_entity(world)).build()
+}
+
+fn make_entity_raw_meat<'a>(builder: EntityBuilder<'a>) -> EntityBuilder<'a> {
+    builder
+        .with(Name {
+            name: "Raw Meat".to_string(),
+        })
+        .with(Renderable {
+            glyph: to_cp437('%'),
+            fg: RGB::named(rltk::INDIANRED),
+            bg: RGB::named(rltk::BLACK),
+            layer: 1,
+        })
+        .with(Item {})
+        .with(Bulk {
+            weight: 2,
+            volume: 1,
+        })
+        .with(Stackable { quantity: 1 })
+        .with(Edible {
+            nutrition: RAW_MEAT_NUTRITION,
+        })
+        .with(Cookable {
+            cooked_name: "Cooked Meat".to_string(),
+            cooked_nutrition: COOKED_MEAT_NUTRITION,
+        })
+}
+
+fn spawn_raw_meat_with_position(world: &mut World, idx: usize, level: &Level) -> Entity {
+    make_entity_raw_meat(create_marked_entity_with_position(world, idx, level)).build()
+}
+
+fn spawn_raw_meat(world: &mut World) -> Entity {
+    make_entity_raw_meat(create_marked_entity(world)).build()
+}
+
 fn make_entity_magic_missile_scroll<'a>(builder: EntityBuilder<'a>) -> EntityBuilder<'a> {
     builder
         .with(Name {
@@ -333,6 +454,16 @@ fn make_entity_magic_missile_scroll<'a>(builder: EntityBuilder<'a>) -> EntityBui
             layer: 1,
         })
         .with(Item {})
+        .with(Bulk {
+            weight: 1,
+            volume: 1,
+        })
+        .with(Stackable { quantity: 1 })
+        .with(Identifiable {
+            kind: IdentifiableKind::Scroll,
+            name: "Scroll of Magic Missile".to_string(),
+            description: "Reading it sends a bolt of force at a single target.".to_string(),
+        })
         .with(Consumable {})
         .with(Ranged { range: 6 })
         .with(CausesDamage {
@@ -367,6 +498,18 @@ fn make_entity_fireball_scroll<'a>(builder: EntityBuilder<'a>) -> EntityBuilder<
             layer: 1,
         })
         .with(Item {})
+        .with(Bulk {
+            weight: 1,
+            volume: 1,
+        })
+        .with(Stackable { quantity: 1 })
+        .with(Identifiable {
+            kind: IdentifiableKind::Scroll,
+            name: "Scroll of Fireball".to_string(),
+            description:
+                "Reading it hurls a ball of fire that burns everything caught in the blast."
+                    .to_string(),
+        })
         .with(Consumable {})
         .with(Ranged { range: 6 })
         .with(CausesDamage {
@@ -399,6 +542,16 @@ fn make_entity_paralyze_scroll<'a>(builder: EntityBuilder<'a>) -> EntityBuilder<
             layer: 1,
         })
         .with(Item {})
+        .with(Bulk {
+            weight: 1,
+            volume: 1,
+        })
+        .with(Stackable { quantity: 1 })
+        .with(Identifiable {
+            kind: IdentifiableKind::Scroll,
+            name: "Scroll of Paralyze".to_string(),
+            description: "Reading it holds a target in place for a few turns.".to_string(),
+        })
         .with(Consumable {})
         .with(Ranged { range: 6 })
         .with(Paralyze { turns: 4 })
@@ -412,6 +565,41 @@ fn spawn_paralyze_scroll(world: &mut World) -> Entity {
     make_entity_paralyze_scroll(create_marked_entity(world)).build()
 }
 
+fn make_entity_identify_scroll<'a>(builder: EntityBuilder<'a>) -> EntityBuilder<'a> {
+    builder
+        .with(Name {
+            name: "Scroll of Identify".to_string(),
+        })
+        .with(Renderable {
+            glyph: to_cp437(')'),
+            fg: RGB::named(rltk::WHITE),
+            bg: RGB::named(rltk::BLACK),
+            layer: 1,
+        })
+        .with(Item {})
+        .with(Bulk {
+            weight: 1,
+            volume: 1,
+        })
+        .with(Stackable { quantity: 1 })
+        .with(Identifiable {
+            kind: IdentifiableKind::Scroll,
+            name: "Scroll of Identify".to_string(),
+            description: "Reading it reveals what one of your unidentified items really is."
+                .to_string(),
+        })
+        .with(Consumable {})
+        .with(Identifies {})
+}
+
+fn spawn_identify_scroll_with_position(world: &mut World, idx: usize, level: &Level) -> Entity {
+    make_entity_identify_scroll(create_marked_entity_with_position(world, idx, level)).build()
+}
+
+fn spawn_identify_scroll(world: &mut World) -> Entity {
+    make_entity_identify_scroll(create_marked_entity(world)).build()
+}
+
 fn make_entity_bear_trap<'a>(builder: EntityBuilder<'a>) -> EntityBuilder<'a> {
     builder
         .with(Name {
@@ -424,6 +612,11 @@ fn make_entity_bear_trap<'a>(builder: EntityBuilder<'a>) -> EntityBuilder<'a> {
             layer: 1,
         })
         .with(Item {})
+        .with(Bulk {
+            weight: 10,
+            volume: 4,
+        })
+        .with(Stackable { quantity: 1 })
         .with(Consumable {})
         .with(Ranged { range: 1 })
         .with(Trap {
@@ -452,6 +645,11 @@ fn make_entity_caltrops<'a>(builder: EntityBuilder<'a>) -> EntityBuilder<'a> {
             layer: 1,
         })
         .with(Item {})
+        .with(Bulk {
+            weight: 3,
+            volume: 2,
+        })
+        .with(Stackable { quantity: 1 })
         .with(Consumable {})
         .with(Ranged { range: 3 })
         .with(Trap {
@@ -576,29 +774,75 @@ fn spawn_set_traps(world: &mut World, idx: usize, level: &Level) {
 }
 
 fn spawn_random_item_with_position(world: &mut World, idx: usize, level: &Level) {
-    let roll = get_random_from_world(world, 0, 7);
+    let roll = get_random_from_world(world, 0, 8);
     match roll {
         1 | 2 => spawn_health_potion_with_position(world, idx, level),
         3 => spawn_fireball_scroll_with_position(world, idx, level),
         4 => spawn_paralyze_scroll_with_position(world, idx, level),
         5 => spawn_bear_trap_with_position(world, idx, level),
         6 => spawn_caltrops_with_position(world, idx, level),
+        7 => spawn_identify_scroll_with_position(world, idx, level),
         _ => spawn_magic_missile_scroll_with_position(world, idx, level),
     };
 }
 
 fn spawn_random_item(world: &mut World) -> Entity {
-    let roll = get_random_from_world(world, 0, 7);
+    let roll = get_random_from_world(world, 0, 8);
     match roll {
         1 | 2 => spawn_health_potion(world),
         3 => spawn_fireball_scroll(world),
         4 => spawn_paralyze_scroll(world),
         5 => spawn_bear_trap(world),
         6 => spawn_caltrops(world),
+        7 => spawn_identify_scroll(world),
         _ => spawn_magic_missile_scroll(world),
     }
 }
 
+fn spawn_random_food_with_position(world: &mut World, idx: usize, level: &Level) {
+    match get_random_from_world(world, 0, 2) {
+        1 => spawn_raw_meat_with_position(world, idx, level),
+        _ => spawn_bread_with_position(world, idx, level),
+    };
+}
+
+fn spawn_random_food(world: &mut World) -> Entity {
+    match get_random_from_world(world, 0, 2) {
+        1 => spawn_raw_meat(world),
+        _ => spawn_bread(world),
+    }
+}
+
+// rooms where food is kept mostly stock food rather than the usual items
+fn spawns_food(room: &Room) -> bool {
+    matches!(
+        room.room_type,
+        Some(RoomType::Kitchen)
+            | Some(RoomType::DiningRoom)
+            | Some(RoomType::MessHall)
+            | Some(RoomType::StoreRoom)
+    )
+}
+
+fn spawn_random_item_for_room_with_position(
+    world: &mut World,
+    room: &Room,
+    idx: usize,
+    level: &Level,
+) {
+    match spawns_food(room) && get_random_from_world(world, 0, 4) > 0 {
+        true => spawn_random_food_with_position(world, idx, level),
+        false => spawn_random_item_with_position(world, idx, level),
+    }
+}
+
+fn spawn_random_item_for_room(world: &mut World, room: &Room) -> Entity {
+    match spawns_food(room) && get_random_from_world(world, 0, 4) > 0 {
+        true => spawn_random_food(world),
+        false => spawn_random_item(world),
+    }
+}
+
 fn get_containers_in_room(world: &World, room: &Room, level_width: u32) -> Vec<Entity> {
     let containers = world.read_storage::<Container>();
     let positions = world.read_storage::<Position>();
@@ -613,6 +857,30 @@ fn get_containers_in_room(world: &World, room: &Room, level_width: u32) -> Vec<E
         .collect()
 }
 
+fn get_containers_with_room_for(world: &World, containers: &[Entity], item: Entity) -> Vec<Entity> {
+    let container_storage = world.read_storage::<Container>();
+    let capacities = world.read_storage::<Capacity>();
+    let bulks = world.read_storage::<Bulk>();
+    let stackables = world.read_storage::<Stackable>();
+    let item_bulk = inventory::get_bulk_of_item(item, &bulks, &stackables);
+    containers
+        .iter()
+        .filter(|container_ent| {
+            match (
+                container_storage.get(**container_ent),
+                capacities.get(**container_ent),
+            ) {
+                (Some(container), Some(capacity)) => capacity.fits(
+                    &inventory::get_bulk_of_items(container.items.iter(), &bulks, &stackables),
+                    &item_bulk,
+                ),
+                _ => true,
+            }
+        })
+        .copied()
+        .collect()
+}
+
 pub fn spawn_item_entities_for_room(world: &mut World, room: &Room, level: &Level) {
     let containers_in_room = get_containers_in_room(world, room, level.width as u32);
     let min_items = match room.room_type {
@@ -633,18 +901,27 @@ pub fn spawn_item_entities_for_room(world: &mut World, room: &Room, level: &Leve
             level_utils::get_spawn_points(&room.rect, level, &mut rng, num_items_not_in_containers)
         };
         for idx in spawn_points.iter() {
-            spawn_random_item_with_position(world, *idx, level);
+            spawn_random_item_for_room_with_position(world, room, *idx, level);
         }
         for _ in 0..num_items_in_containers {
-            let item = spawn_random_item(world);
+            let item = spawn_random_item_for_room(world, room);
+            let containers_with_room =
+                get_containers_with_room_for(world, &containers_in_room, item);
             let container_ent = {
                 let mut rng = world.fetch_mut::<RandomNumberGenerator>();
-                rng.random_slice_entry(containers_in_room.as_slice())
+                rng.random_slice_entry(containers_with_room.as_slice())
+                    .copied()
             };
-            if let Some(container_ent) = container_ent {
-                let mut containers = world.write_storage::<Container>();
-                let container = containers.get_mut(*container_ent).unwrap();
-                container.items.insert(item);
+            match container_ent {
+                Some(container_ent) => {
+                    let mut containers = world.write_storage::<Container>();
+                    let container = containers.get_mut(container_ent).unwrap();
+                    container.items.insert(item);
+                }
+                // every container in the room is full so the item is never placed
+                None => world
+                    .delete_entity(item)
+                    .expect("could not delete item with no room in containers"),
             }
         }
     }
@@ -790,6 +1067,13 @@ pub fn spawn_stove(world: &mut World, idx: usize, level: &mut Level) {
         'S',
         RGB::named(rltk::BROWN3),
     )
+    .with(CookingSpot {})
+    .with(CausesLight {
+        radius: 2,
+        lit: false,
+        turns_remaining: None,
+    })
+    .with(Lightable {})
     .build();
     level.blocked[idx] = true;
 }
@@ -801,6 +1085,13 @@ pub fn spawn_cupboard(world: &mut World, idx: usize, level: &mut Level) {
         'c',
         RGB::named(rltk::BROWN3),
     )
+    .with(Container {
+        items: EntitySet::new(),
+    })
+    .with(Capacity {
+        max_weight: 40,
+        max_volume: 12,
+    })
     .build();
     level.blocked[idx] = true;
 }
@@ -823,6 +1114,13 @@ pub fn spawn_barrel(world: &mut World, idx: usize, level: &mut Level) {
         RGB::named(rltk::YELLOW),
     )
     .with(HidingSpot {})
+    .with(Container {
+        items: EntitySet::new(),
+    })
+    .with(Capacity {
+        max_weight: 60,
+        max_volume: 16,
+    })
     .build();
     level.blocked[idx] = true;
 }
@@ -837,6 +1135,10 @@ pub fn spawn_treasure_chest(world: &mut World, idx: usize, level: &mut Level) {
     .with(Container {
         items: EntitySet::new(),
     })
+    .with(Capacity {
+        max_weight: 100,
+        max_volume: 20,
+    })
     .build();
     level.blocked[idx] = true;
 }
@@ -876,6 +1178,28 @@ pub fn spawn_door(world: &mut World, idx: usize, level: &mut Level) {
     level.opaque[idx] = true
 }
 
+pub fn spawn_secret_door(world: &mut World, idx: usize, level: &mut Level) {
+    create_marked_entity_with_position(world, idx, level)
+        .with(Name {
+            name: "Secret Door".to_string(),
+        })
+        .with(Renderable {
+            glyph: to_cp437('▲'),
+            fg: RGB::named(rltk::BROWN4),
+            bg: RGB::named(rltk::BLACK),
+            layer: 1,
+        })
+        .with(Door {
+            state: DoorState::Closed,
+        })
+        .with(Hidden {
+            found_by: EntitySet::new(),
+        })
+        .build();
+    level.blocked[idx] = true;
+    level.opaque[idx] = true
+}
+
 pub fn spawn_entities_for_room(world: &mut World, room: &Room, level: &mut Level) {
     spawn_item_entities_for_room(world, room, level);
 }
@@ -906,7 +1230,14 @@ pub fn spawn_entites_from_stamps(
             RoomPart::Throne => spawn_throne(world, *idx, level),
             RoomPart::Podium => spawn_podium(world, *idx, level),
             RoomPart::Sconce => spawn_sconce(world, *idx, level),
-            RoomPart::Door => spawn_door(world, *idx, level),
+            RoomPart::Door => match level.secret_doors.contains(idx) {
+                true => spawn_secret_door(world, *idx, level),
+                false => spawn_door(world, *idx, level),
+            },
+            RoomPart::MonsterSpawn => {
+                spawn_goblin(world, *idx, level);
+            }
+            RoomPart::ItemSpawn => spawn_random_item_with_position(world, *idx, level),
             _ => (),
         };
     }
@@ -918,31 +1249,49 @@ fn spawn_set_traps_for_level(world: &mut World, level: &mut Level) {
         .for_each(|idx| spawn_set_traps(world, *idx, level));
 }
 
-fn spawn_goblins_for_level(world: &mut World, level: &mut Level) {
+// Levels without rooms have nowhere to keep items so they are left lying around instead.
+fn spawn_scattered_items_for_level(world: &mut World, level: &mut Level) {
     get_random_spawn_points_for_level(
         world,
         level,
-        MIN_GOBLIN_GROUPS_PER_LEVEL,
-        MAX_GOBLIN_GROUPS_PER_LEVEL,
+        MIN_SCATTERED_ITEMS_PER_LEVEL,
+        MAX_SCATTERED_ITEMS_PER_LEVEL,
     )
     .iter()
-    .for_each(|idx| {
-        let mut possible_spawn_points_for_group =
-            level_utils::get_all_spawnable_tiles_in_radius(level, *idx, MAX_GOBLIN_SPACING as u32);
-        let goblin_count =
-            get_random_from_world(world, MIN_GOBLINS_PER_GROUP, MAX_GOBLINS_PER_GROUP);
-        let spawn_points = {
-            let mut rng = world.write_resource::<RandomNumberGenerator>();
-            utils::get_x_random_elements(
-                &mut rng,
-                goblin_count as u32,
-                &mut possible_spawn_points_for_group,
-            )
-        };
-        spawn_points.iter().for_each(|idx| {
-            spawn_goblin(world, *idx, level);
+    .for_each(|idx| spawn_random_item_with_position(world, *idx, level));
+}
+
+fn spawn_goblins_for_level(world: &mut World, level: &mut Level) {
+    let (min_groups, max_groups) = match level.id.branch {
+        Branch::GoblinWarren => (
+            MIN_GOBLIN_GROUPS_PER_LEVEL * 2,
+            MAX_GOBLIN_GROUPS_PER_LEVEL * 2,
+        ),
+        _ => (MIN_GOBLIN_GROUPS_PER_LEVEL, MAX_GOBLIN_GROUPS_PER_LEVEL),
+    };
+    get_random_spawn_points_for_level(world, level, min_groups, max_groups)
+        .iter()
+        .for_each(|idx| {
+            let mut possible_spawn_points_for_group =
+                level_utils::get_all_spawnable_tiles_in_radius(
+                    level,
+                    *idx,
+                    MAX_GOBLIN_SPACING as u32,
+                );
+            let goblin_count =
+                get_random_from_world(world, MIN_GOBLINS_PER_GROUP, MAX_GOBLINS_PER_GROUP);
+            let spawn_points = {
+                let mut rng = world.write_resource::<RandomNumberGenerator>();
+                utils::get_x_random_elements(
+                    &mut rng,
+                    goblin_count as u32,
+                    &mut possible_spawn_points_for_group,
+                )
+            };
+            spawn_points.iter().for_each(|idx| {
+                spawn_goblin(world, *idx, level);
+            });
         });
-    });
 }
 
 pub fn spawn_entities_for_level(world: &mut World, level: &mut Level) {
@@ -971,6 +1320,9 @@ pub fn spawn_entities_for_level(world: &mut World, level: &mut Level) {
         let room = level.rooms[i].clone();
         spawn_entities_for_room(world, &room, level);
     }
+    if count == 0 {
+        spawn_scattered_items_for_level(world, level);
+    }
     spawn_goblins_for_level(world, level);
     spawn_set_traps_for_level(world, level);
 }
@@ -982,3 +1334,11 @@ pub fn spawn_objective_for_room(ecs: &mut World, rect: &Rect, level: &Level) {
     };
     spawn_objective(ecs, idx, level);
 }
+
+pub fn spawn_objective_for_level(ecs: &mut World, level: &Level) {
+    let idx = {
+        let mut rng = ecs.write_resource::<RandomNumberGenerator>();
+        level_utils::get_random_unblocked_floor_point(level, &mut rng).unwrap()
+    };
+    spawn_objective(ecs, idx, level);
+}
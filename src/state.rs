@@ -1,40 +1,60 @@
 #[cfg(debug_assertions)]
 use crate::debug;
 use crate::{
+    audio::{self, AudioBackend, AudioChannel, MusicDirector},
+    auto_explore::{self, AutoExploreStep},
     components::{
         door::DoorState, equipable::EquipmentPositions, Armable, CombatStats, Container,
-        Disarmable, Door, Dousable, Equipable, Equipment, Grabbable, Grabbing, Hidden, HidingSpot,
-        Info, Inventory, Item, Lightable, Name, Objective, Position, Ranged, Trap, Viewshed,
-        WantsToDropItem,
+        CookingSpot, Disarmable, Door, Dousable, Edible, Equipable, Equipment, Grabbable, Grabbing,
+        Hidden, HidingSpot, Info, Inventory, Item, Lightable, Name, Position, Ranged, Trap,
+        Viewshed,
     },
+    control::Control,
     copy,
-    dungeon::{dungeon::Dungeon, level_builders, level_utils, tile_type::TileType},
+    dungeon::{
+        branch::{Branch, SIDE_BRANCHES},
+        dungeon::Dungeon,
+        level_builders,
+        level_id::LevelId,
+        level_utils,
+        tile_type::TileType,
+    },
+    gamepad,
     interaction_type::InteractionType,
     inventory,
     menu::{Menu, MenuOption, MenuOptionState},
-    music::Music,
+    options::OptionsTab,
     patches, persistence, player, ranged,
+    rest::{self, RestStep},
     run_state::{RunState, TargetIntent},
     screens::{
+        screen_message_log::MESSAGE_LOG_PAGE_SIZE,
+        screen_options::OPTIONS_PER_PAGE,
+        sprite_sheet,
+        utils::{get_player_view_center, get_render_offset_for_xy},
+        viewport::Viewport,
         ScreenCredits, ScreenDeath, ScreenFailure, ScreenIntro, ScreenLoading, ScreenMainMenu,
-        ScreenMapGeneric, ScreenMapInteractMenu, ScreenMapInteractTarget, ScreenMapMenu,
-        ScreenMapNestedMenu, ScreenMapTargeting, ScreenNewGame, ScreenOptions, ScreenSaving,
-        ScreenSetKey, ScreenSuccess,
+        ScreenMapExamine, ScreenMapGeneric, ScreenMapInteractMenu, ScreenMapInteractTarget,
+        ScreenMapMenu, ScreenMapNestedMenu, ScreenMapOverview, ScreenMapTargeting,
+        ScreenMessageLog, ScreenNewGame, ScreenOptions, ScreenSaving, ScreenSetKey, ScreenSuccess,
+        MAP_HEIGHT,
     },
-    services::GameLog,
-    settings::Settings,
+    services::{game_log::LogCategory, GameLog},
+    settings::{ControlScheme, Preferences, Settings},
     spawner,
     systems::{
-        BloodSpawnSystem, CloseDoorSystem, CorpseSpawnSystem, DamageSystem, DebrisSpawnSystem,
-        DisarmTrapSystem, DouseItemSystem, EquipSystem, FireBurnSystem, FireDieSystem,
-        FireSpreadSystem, GoDownStairsSystem, GoUpStairsSystem, GrabSystem, HideSystem,
-        ItemCollectionSystem, ItemDropSystem, ItemSpawnSystem, LightItemSystem, LightSystem,
-        MapIndexingSystem, MeleeCombatSystem, MemoryCullSystem, MonsterAI, MoveSystem,
-        OpenDoorSystem, ParticleSpawnSystem, ReleaseSystem, RemoveParticleEffectsSystem,
-        RemoveTriggeredTrapsSystem, RevealTrapsSystem, SearchForHiddenSystem, SetTrapSystem,
-        TrapSpawnSystem, TriggerSystem, UpdateMemoriesSystem, UpdateParticleEffectsSystem,
-        UseItemSystem, VisibilitySystem,
+        BloodSpawnSystem, CloseDoorSystem, CookSystem, CorpseSpawnSystem, DamageSystem,
+        DebrisSpawnSystem, DisarmTrapSystem, DouseItemSystem, EatSystem, EquipSystem,
+        FireBurnSystem, FireDieSystem, FireSpreadSystem, GoDownStairsSystem, GoUpStairsSystem,
+        GrabSystem, HideSystem, HungerSystem, IdentifySystem, ItemCollectionSystem, ItemDropSystem,
+        ItemSpawnSystem, LightItemSystem, LightSystem, MapIndexingSystem, MeleeCombatSystem,
+        MemoryCullSystem, MonsterAI, MoveSystem, OpenDoorSystem, ParticleSpawnSystem,
+        RegenerationSystem, ReleaseSystem, RemoveParticleEffectsSystem, RemoveTriggeredTrapsSystem,
+        RevealSecretDoorsSystem, RevealTrapsSystem, RotSystem, SearchForHiddenSystem,
+        SetTrapSystem, SoundEffectSystem, TrapSpawnSystem, TriggerSystem, UpdateMemoriesSystem,
+        UpdateParticleEffectsSystem, UseItemSystem, VisibilitySystem,
     },
+    travel,
     types::EquipMenuType,
     user_actions::{
         InteractionTargetingAction, MapAction, MenuAction, StaticAction, TargetingAction,
@@ -68,20 +88,6 @@ fn player_can_leave_dungeon(world: &mut World) -> bool {
     }
 }
 
-fn has_objective_in_backpack(world: &World) -> bool {
-    let player_ent = world.fetch::<Entity>();
-    let inventories = world.read_storage::<Inventory>();
-    let player_inventory = inventories.get(*player_ent).unwrap();
-    let entities = world.entities();
-    let objectives = world.read_storage::<Objective>();
-    for (entity, _objective) in (&entities, &objectives).join() {
-        if player_inventory.items.contains(&entity) {
-            return true;
-        }
-    }
-    false
-}
-
 fn get_visible_entities(world: &World) -> Box<[Entity]> {
     let player_ent = world.fetch::<Entity>();
     let viewsheds = world.read_storage::<Viewshed>();
@@ -166,6 +172,7 @@ fn filter_targets_are_interactable(world: &World, targets: Box<[Entity]>) -> Box
     let containers = world.read_storage::<Container>();
     let items = world.read_storage::<Item>();
     let doors = world.read_storage::<Door>();
+    let edibles = world.read_storage::<Edible>();
     targets
         .iter()
         .filter(|e| {
@@ -178,6 +185,7 @@ fn filter_targets_are_interactable(world: &World, targets: Box<[Entity]>) -> Box
                 || items.get(**e).is_some()
                 || containers.get(**e).is_some()
                 || doors.get(**e).is_some()
+                || edibles.get(**e).is_some()
         })
         .map(|e| *e)
         .collect()
@@ -227,6 +235,15 @@ fn get_interaction_options_for_target(world: &World, target: Entity) -> Vec<Inte
     if world.read_storage::<Container>().get(target).is_some() {
         interactions.push(InteractionType::OpenContainer(target));
     }
+    // food that can be carried is eaten from the inventory instead
+    if world.read_storage::<Edible>().get(target).is_some()
+        && world.read_storage::<Item>().get(target).is_none()
+    {
+        interactions.push(InteractionType::Eat(target));
+    }
+    if world.read_storage::<CookingSpot>().get(target).is_some() {
+        interactions.push(InteractionType::Cook(target));
+    }
     if let Some(door) = world.read_storage::<Door>().get(target) {
         interactions.push(match door.state {
             DoorState::Closed => InteractionType::OpenDoor(target),
@@ -287,22 +304,54 @@ fn intent_to_interaction_type(intent: TargetIntent, target: Entity) -> Interacti
     }
 }
 
-fn generate_dungeon(world: &mut World, levels: u8) -> Dungeon {
-    let levels = (0..levels).fold(HashMap::new(), |mut acc, floor_number| {
-        let is_top_floor = floor_number == levels - 1;
-        let is_bottom_floor = floor_number == 0;
-        let mut level = level_builders::build(floor_number, is_top_floor, is_bottom_floor);
+fn generate_dungeon(world: &mut World) -> Dungeon {
+    let level_ids = iter::once(Branch::Main)
+        .chain(SIDE_BRANCHES.iter().cloned())
+        .map(|branch| branch.get_level_ids())
+        .flatten();
+    let levels = level_ids.fold(HashMap::new(), |mut acc, level_id| {
+        let mut builder = {
+            let mut rng = world.write_resource::<RandomNumberGenerator>();
+            level_builders::get_builder_for_level(level_id, &mut rng)
+        };
+        let mut level = level_builders::build(builder.as_mut(), level_id);
         spawner::spawn_entities_for_level(world, &mut level);
-        acc.insert(floor_number, level);
+        acc.insert(level_id, level);
         return acc;
     });
     Dungeon { levels }
 }
 
+pub fn apply_preferences(
+    preferences: &Preferences,
+    world: &mut World,
+    audio: &mut dyn AudioBackend,
+) {
+    world.insert(preferences.render_mode);
+    world.insert(preferences.message_verbosity);
+    {
+        let mut viewport = world.fetch_mut::<Viewport>();
+        viewport.show_minimap = preferences.show_minimap;
+        viewport.margin = preferences.viewport_margin;
+    }
+    audio.set_volume(AudioChannel::Music, preferences.get_music_volume());
+    audio.set_volume(
+        AudioChannel::SoundEffects,
+        preferences.get_sound_effect_volume(),
+    );
+}
+
+fn get_options_row_text(label: &str, value: &str) -> String {
+    let space_count = 50usize.saturating_sub(label.len() + value.len());
+    let space_text: String = (0..space_count).map(|_| " ").collect();
+    format!("{}{}{}", label, space_text, value)
+}
+
 fn initialize_new_game(world: &mut World) {
     world_utils::initialize_new_game(world);
-    let dungeon = generate_dungeon(world, 10);
-    let level = dungeon.get_level(9).unwrap();
+    let dungeon = generate_dungeon(world);
+    let top_level_id = LevelId::new(Branch::Main, Branch::Main.get_top_depth());
+    let level = dungeon.get_level(top_level_id).unwrap();
     let (player_idx, _) = level
         .tiles
         .iter()
@@ -313,11 +362,22 @@ fn initialize_new_game(world: &mut World) {
     let player_entity = spawner::spawn_player(world, player_idx, level);
     world.insert(player_entity);
     let rng = world.get_mut::<RandomNumberGenerator>().unwrap();
-    let objective_floor = utils::get_random_between_numbers(rng, 1, 9) as u8;
-    let level = dungeon.get_level(objective_floor).unwrap();
-    let room_idx = utils::get_random_between_numbers(rng, 0, (level.rooms.len() - 1) as i32);
-    let room = level.rooms.get(room_idx as usize).unwrap();
-    spawner::spawn_objective_for_room(world, &room.rect, &level);
+    let objective_depth = utils::get_random_between_numbers(
+        rng,
+        Branch::Main.get_bottom_depth() as i32 + 1,
+        Branch::Main.get_top_depth() as i32,
+    ) as u8;
+    let level = dungeon
+        .get_level(LevelId::new(Branch::Main, objective_depth))
+        .unwrap();
+    match level.rooms.len() {
+        0 => spawner::spawn_objective_for_level(world, &level),
+        room_count => {
+            let room_idx = utils::get_random_between_numbers(rng, 0, (room_count - 1) as i32);
+            let room = level.rooms.get(room_idx as usize).unwrap();
+            spawner::spawn_objective_for_room(world, &room.rect, &level);
+        }
+    }
     world.remove::<Dungeon>();
     world.insert(dungeon);
 }
@@ -353,6 +413,140 @@ fn get_idx_from_delta(world: &World, delta_x: i32, delta_y: i32) -> usize {
     ) as usize
 }
 
+// how many times the repeat menu offers to repeat an action
+const REPEAT_OPTIONS: [(&str, u32); 4] = [
+    ("5 times", 5),
+    ("10 times", 10),
+    ("20 times", 20),
+    ("50 times", 50),
+];
+
+// repeats and macros feed their actions back in as if they were pressed, one turn at a time
+fn take_queued_map_action(
+    queued_action: &mut Option<InteractionType>,
+    macros: &[Vec<MapAction>],
+) -> Option<MapAction> {
+    match *queued_action {
+        Some(InteractionType::Repeat(action, remaining)) => {
+            *queued_action = match remaining > 1 {
+                true => Some(InteractionType::Repeat(action, remaining - 1)),
+                false => None,
+            };
+            Some(action)
+        }
+        Some(InteractionType::PlayMacro(slot, step)) => {
+            let actions = &macros[slot];
+            *queued_action = match step + 1 < actions.len() {
+                true => Some(InteractionType::PlayMacro(slot, step + 1)),
+                false => None,
+            };
+            // only replay what could have been recorded, in case the file was edited by hand
+            actions
+                .get(step)
+                .copied()
+                .filter(|action| action.is_repeatable())
+        }
+        _ => None,
+    }
+}
+
+fn toggle_macro_recording(
+    world: &World,
+    recording_macro: &mut Option<(usize, Vec<MapAction>)>,
+    control_scheme: &mut ControlScheme,
+    slot: usize,
+) {
+    let mut log = world.fetch_mut::<GameLog>();
+    match recording_macro.take() {
+        Some((recorded_slot, actions)) => {
+            control_scheme.macros[recorded_slot] = actions;
+            control_scheme.save();
+            log.add_system(copy::MACRO_SAVED.to_string());
+        }
+        None => {
+            *recording_macro = Some((slot, vec![]));
+            log.add_system(copy::MACRO_RECORDING.to_string());
+        }
+    }
+}
+
+fn handle_rest(world: &mut World) -> RunState {
+    let message = match rest::get_next_step(world) {
+        RestStep::Rest => return RunState::PlayerTurn,
+        RestStep::Healed => copy::REST_HEALED,
+        RestStep::Burning => copy::REST_BURNING,
+        RestStep::Hungry => copy::REST_HUNGRY,
+    };
+    let mut log = world.fetch_mut::<GameLog>();
+    log.add_system(message.to_string());
+    RunState::AwaitingInput {
+        offset_x: 0,
+        offset_y: 0,
+    }
+}
+
+fn get_travel_action(
+    world: &World,
+    destination: Option<usize>,
+    unknown_message: &str,
+) -> Option<InteractionType> {
+    match destination {
+        Some(idx) if idx != get_player_idx(world) => Some(InteractionType::Move(idx)),
+        Some(_) => None,
+        None => {
+            let mut log = world.fetch_mut::<GameLog>();
+            log.add_system(unknown_message.to_string());
+            None
+        }
+    }
+}
+
+fn handle_auto_explore(world: &mut World) -> RunState {
+    match auto_explore::get_next_step(world) {
+        AutoExploreStep::Move(idx) => {
+            player::move_to_position(world, idx);
+            RunState::PlayerTurn
+        }
+        AutoExploreStep::OpenDoor(door) => {
+            player::interact(world, InteractionType::OpenDoor(door));
+            RunState::PlayerTurn
+        }
+        AutoExploreStep::Blocked => {
+            let mut log = world.fetch_mut::<GameLog>();
+            log.add_system(copy::AUTO_EXPLORE_BLOCKED.to_string());
+            RunState::AwaitingInput {
+                offset_x: 0,
+                offset_y: 0,
+            }
+        }
+        AutoExploreStep::Done => {
+            let mut log = world.fetch_mut::<GameLog>();
+            log.add_system(copy::AUTO_EXPLORE_DONE.to_string());
+            RunState::AwaitingInput {
+                offset_x: 0,
+                offset_y: 0,
+            }
+        }
+    }
+}
+
+fn get_player_idx(world: &World) -> usize {
+    let positions = world.read_storage::<Position>();
+    let player_entity = world.fetch::<Entity>();
+    positions.get(*player_entity).unwrap().idx
+}
+
+fn move_examine_cursor(world: &World, idx: usize, delta_x: i32, delta_y: i32) -> usize {
+    let dungeon = world.fetch::<Dungeon>();
+    let level = dungeon
+        .get_level(get_current_level_from_world(world))
+        .unwrap();
+    let (x, y) = level_utils::idx_xy(level.width as u32, idx);
+    let x = (x + delta_x).max(0).min(level.width as i32 - 1);
+    let y = (y + delta_y).max(0).min(level.height as i32 - 1);
+    level_utils::xy_idx(level.width as u32, x, y)
+}
+
 pub fn handle_move(world: &mut World, delta_x: i32, delta_y: i32) -> RunState {
     let destination_idx = get_idx_from_delta(world, delta_x, delta_y);
     player::move_to_position(world, destination_idx);
@@ -376,6 +570,42 @@ pub fn handle_act(world: &mut World, delta_x: i32, delta_y: i32) -> RunState {
     }
 }
 
+fn get_revealed_idx_at_mouse(
+    world: &World,
+    ctx: &Rltk,
+    offset_x: i32,
+    offset_y: i32,
+) -> Option<usize> {
+    let (mouse_x, mouse_y) = ctx.mouse_pos();
+    if mouse_y >= MAP_HEIGHT as i32 {
+        return None;
+    }
+    let (center_x, center_y) = get_player_view_center(world);
+    let dungeon = world.fetch::<Dungeon>();
+    let level = dungeon
+        .get_level(utils::get_current_level_from_world(world))
+        .unwrap();
+    let (x, y) =
+        get_render_offset_for_xy(center_x + offset_x, center_y + offset_y, mouse_x, mouse_y);
+    if x < 0 || y < 0 || x >= level.width as i32 || y >= level.height as i32 {
+        return None;
+    }
+    let idx = level_utils::xy_idx(level.width as u32, x, y);
+    match level.revealed_tiles[idx] {
+        true => Some(idx),
+        false => None,
+    }
+}
+
+fn idx_is_within_reach(world: &World, idx: usize) -> bool {
+    let player_idx = get_player_idx(world);
+    let dungeon = world.fetch::<Dungeon>();
+    let level = dungeon
+        .get_level(utils::get_current_level_from_world(world))
+        .unwrap();
+    idx == player_idx || level_utils::idxs_are_adjacent(level.width, player_idx, idx)
+}
+
 fn get_player_path_to_target(world: &World, target_idx: usize) -> NavigationPath {
     let player_entity = world.fetch::<Entity>();
     let positions = world.read_storage::<Position>();
@@ -391,8 +621,13 @@ pub struct State {
     pub world: World,
     pub run_state: RunState,
     pub queued_action: Option<InteractionType>,
+    pub last_action: Option<MapAction>,
+    // the macro slot being recorded and the actions so far
+    pub recording_macro: Option<(usize, Vec<MapAction>)>,
     pub settings: Settings,
-    pub music: Music,
+    pub audio: Box<dyn AudioBackend>,
+    pub music_director: MusicDirector,
+    pub monsters_in_view: bool,
 }
 
 impl State {
@@ -409,10 +644,14 @@ impl State {
         {
             let mut equip_system = EquipSystem {};
             equip_system.run_now(&self.world);
+            let mut identify_system = IdentifySystem {};
+            identify_system.run_now(&self.world);
             let mut light = LightSystem {};
             light.run_now(&self.world);
             let mut vis = VisibilitySystem {
                 queued_action: &mut self.queued_action,
+                explore_interrupts: self.settings.preferences.explore_interrupts,
+                monsters_in_view: &mut self.monsters_in_view,
             };
             vis.run_now(&self.world);
             let mut update_memories_system = UpdateMemoriesSystem {};
@@ -441,6 +680,12 @@ impl State {
             fire_spread_system.run_now(&self.world);
             let mut fire_die_system = FireDieSystem {};
             fire_die_system.run_now(&self.world);
+            let mut hunger_system = HungerSystem {};
+            hunger_system.run_now(&self.world);
+            let mut rot_system = RotSystem {};
+            rot_system.run_now(&self.world);
+            let mut regeneration_system = RegenerationSystem {};
+            regeneration_system.run_now(&self.world);
         }
         let mut damage = DamageSystem {
             queued_action: &mut self.queued_action,
@@ -465,6 +710,8 @@ impl State {
         if self.run_state == RunState::PlayerTurn || self.run_state == RunState::MonsterTurn {
             let mut search_for_hidden_system = SearchForHiddenSystem {};
             search_for_hidden_system.run_now(&self.world);
+            let mut reveal_secret_doors_system = RevealSecretDoorsSystem {};
+            reveal_secret_doors_system.run_now(&self.world);
             let mut set_trap_system = SetTrapSystem {};
             set_trap_system.run_now(&self.world);
             let mut disarm_trap_system = DisarmTrapSystem {};
@@ -481,6 +728,10 @@ impl State {
             light_item_system.run_now(&self.world);
             let mut douse_item_system = DouseItemSystem {};
             douse_item_system.run_now(&self.world);
+            let mut eat_system = EatSystem {};
+            eat_system.run_now(&self.world);
+            let mut cook_system = CookSystem {};
+            cook_system.run_now(&self.world);
         }
         let mut blood_spawn_system = BloodSpawnSystem {};
         blood_spawn_system.run_now(&self.world);
@@ -494,6 +745,10 @@ impl State {
         debris_spawn_system.run_now(&self.world);
         let mut corpse_spawn_system = CorpseSpawnSystem {};
         corpse_spawn_system.run_now(&self.world);
+        let mut sound_effect_system = SoundEffectSystem {
+            audio: self.audio.as_mut(),
+        };
+        sound_effect_system.run_now(&self.world);
         if self.run_state == RunState::PlayerTurn || self.run_state == RunState::MonsterTurn {
             DamageSystem::delete_the_dead(&mut self.world);
             let mut memory_cull_system = MemoryCullSystem {};
@@ -506,6 +761,13 @@ impl State {
 impl GameState for State {
     fn tick(&mut self, ctx: &mut Rltk) {
         patches::patch_mod_keys(ctx);
+        gamepad::poll(ctx.frame_time_ms);
+        ctx.post_scanlines = self.settings.preferences.scanlines;
+        sprite_sheet::clear_sprites(ctx);
+        let music_context =
+            audio::get_music_context(&self.run_state, &self.world, self.monsters_in_view);
+        self.music_director
+            .update(music_context, self.audio.as_mut(), ctx.frame_time_ms);
         match self.run_state {
             RunState::PreRun
             | RunState::AwaitingInput { .. }
@@ -523,19 +785,34 @@ impl GameState for State {
             }
             RunState::AwaitingInput { offset_x, offset_y } => {
                 ScreenMapGeneric::new(*offset_x, *offset_y).draw(ctx, &mut self.world);
-                let action = self.settings.control_scheme.map.get_value_with_context(ctx);
+                let input_action = self
+                    .settings
+                    .control_scheme
+                    .map
+                    .get_value_with_context(ctx)
+                    .copied();
 
-                if action.is_some() {
+                if input_action.is_some() {
                     self.queued_action = None
                 }
+                if let Some(action) = input_action.filter(|action| action.is_repeatable()) {
+                    self.last_action = Some(action);
+                    if let Some((_, actions)) = self.recording_macro.as_mut() {
+                        actions.push(action);
+                    }
+                }
+                let action = match input_action {
+                    Some(action) => Some(action),
+                    None => take_queued_map_action(
+                        &mut self.queued_action,
+                        &self.settings.control_scheme.macros,
+                    ),
+                };
                 match action {
                     Some(action) => match action {
                         #[cfg(debug_assertions)]
                         MapAction::ShowDebugMenu => RunState::DebugMenu { highlighted: 0 },
-                        MapAction::Exit => {
-                            self.music.pause_music();
-                            RunState::SavingScreen { count_down: 15 }
-                        }
+                        MapAction::Exit => RunState::SavingScreen { count_down: 15 },
                         MapAction::ShowInventoryMenu => RunState::InventoryMenu { highlighted: 0 },
                         MapAction::ShowDropMenu => RunState::DropItemMenu { highlighted: 0 },
                         MapAction::ShowEquipmentMenu => RunState::EquipmentMenu {
@@ -543,15 +820,16 @@ impl GameState for State {
                             action_highlighted: 0,
                             action_menu: false,
                         },
+                        MapAction::ShowMessageLog => RunState::MessageLogScreen {
+                            offset: 0,
+                            filter: None,
+                        },
                         MapAction::LeaveDungeon => {
                             match player_can_leave_dungeon(&mut self.world) {
-                                true => {
-                                    self.music.pause_music();
-                                    RunState::ExitGameMenu { highlighted: 0 }
-                                }
+                                true => RunState::ExitGameMenu { highlighted: 0 },
                                 false => {
                                     let mut log = self.world.fetch_mut::<GameLog>();
-                                    log.add(
+                                    log.add_system(
                                         "You must first locate the exit to leave the dungeon"
                                             .to_string(),
                                     );
@@ -634,6 +912,107 @@ impl GameState for State {
                         MapAction::Interact => {
                             RunState::InteractiveEntityTargeting { target_idx: 0 }
                         }
+                        MapAction::Examine => RunState::ExamineMode {
+                            idx: get_player_idx(&self.world),
+                        },
+                        MapAction::AutoExplore => {
+                            self.queued_action = Some(InteractionType::Explore);
+                            RunState::AwaitingInput {
+                                offset_x: 0,
+                                offset_y: 0,
+                            }
+                        }
+                        MapAction::TravelToDownStairs => {
+                            self.queued_action = get_travel_action(
+                                &self.world,
+                                travel::get_known_down_stairs(&self.world),
+                                copy::TRAVEL_UNKNOWN_DOWN_STAIRS,
+                            );
+                            RunState::AwaitingInput {
+                                offset_x: 0,
+                                offset_y: 0,
+                            }
+                        }
+                        MapAction::TravelToUpStairs => {
+                            self.queued_action = get_travel_action(
+                                &self.world,
+                                travel::get_known_up_stairs(&self.world),
+                                copy::TRAVEL_UNKNOWN_UP_STAIRS,
+                            );
+                            RunState::AwaitingInput {
+                                offset_x: 0,
+                                offset_y: 0,
+                            }
+                        }
+                        MapAction::TravelToExit => {
+                            self.queued_action = get_travel_action(
+                                &self.world,
+                                travel::get_known_exit(&self.world),
+                                copy::TRAVEL_UNKNOWN_EXIT,
+                            );
+                            RunState::AwaitingInput {
+                                offset_x: 0,
+                                offset_y: 0,
+                            }
+                        }
+                        MapAction::ShowLandmarkMenu => {
+                            match travel::get_known_landmarks(&self.world).is_empty() {
+                                true => {
+                                    let mut log = self.world.fetch_mut::<GameLog>();
+                                    log.add_system(copy::TRAVEL_NO_LANDMARKS.to_string());
+                                    RunState::AwaitingInput {
+                                        offset_x: *offset_x,
+                                        offset_y: *offset_y,
+                                    }
+                                }
+                                false => RunState::LandmarkMenu { highlighted: 0 },
+                            }
+                        }
+                        MapAction::ShowOverview => RunState::OverviewScreen,
+                        MapAction::ToggleMinimap => {
+                            let preferences = &mut self.settings.preferences;
+                            preferences.show_minimap = !preferences.show_minimap;
+                            preferences.save();
+                            self.world.fetch_mut::<Viewport>().show_minimap =
+                                preferences.show_minimap;
+                            RunState::AwaitingInput {
+                                offset_x: *offset_x,
+                                offset_y: *offset_y,
+                            }
+                        }
+                        MapAction::TravelToMouse => {
+                            if let Some(idx) =
+                                get_revealed_idx_at_mouse(&self.world, ctx, *offset_x, *offset_y)
+                            {
+                                if idx != get_player_idx(&self.world) {
+                                    self.queued_action = Some(InteractionType::Move(idx));
+                                }
+                            }
+                            RunState::AwaitingInput {
+                                offset_x: *offset_x,
+                                offset_y: *offset_y,
+                            }
+                        }
+                        MapAction::InteractAtMouse => {
+                            match get_revealed_idx_at_mouse(&self.world, ctx, *offset_x, *offset_y)
+                            {
+                                Some(idx)
+                                    if get_interaction_targets_at_idx(&self.world, idx).len()
+                                        > 0 =>
+                                {
+                                    RunState::InteractAtIdx {
+                                        idx,
+                                        interaction_menu_idx: 0,
+                                        target_menu_idx: 0,
+                                        interaction_menu_active: false,
+                                    }
+                                }
+                                _ => RunState::AwaitingInput {
+                                    offset_x: *offset_x,
+                                    offset_y: *offset_y,
+                                },
+                            }
+                        }
                         MapAction::MoveLeft => handle_move(&mut self.world, -1, 0),
                         MapAction::MoveRight => handle_move(&mut self.world, 1, 0),
                         MapAction::MoveUp => handle_move(&mut self.world, 0, -1),
@@ -659,6 +1038,19 @@ impl GameState for State {
                         MapAction::AutoActDownLeft => handle_auto_act(&mut self.world, -1, 1),
                         MapAction::AutoActDownRight => handle_auto_act(&mut self.world, 1, 1),
                         MapAction::StayStill => RunState::PlayerTurn,
+                        MapAction::Rest => {
+                            match self.monsters_in_view {
+                                true => {
+                                    let mut log = self.world.fetch_mut::<GameLog>();
+                                    log.add_system(copy::REST_MONSTERS_IN_VIEW.to_string());
+                                }
+                                false => self.queued_action = Some(InteractionType::Rest),
+                            }
+                            RunState::AwaitingInput {
+                                offset_x: *offset_x,
+                                offset_y: *offset_y,
+                            }
+                        }
                         MapAction::SearchHidden => {
                             player::search_hidden(&mut self.world);
                             RunState::PlayerTurn
@@ -667,13 +1059,67 @@ impl GameState for State {
                             player::release_entity(&mut self.world);
                             RunState::PlayerTurn
                         }
+                        MapAction::RepeatLastAction => match self.last_action {
+                            Some(action) => RunState::RepeatMenu {
+                                highlighted: 0,
+                                action,
+                            },
+                            None => {
+                                let mut log = self.world.fetch_mut::<GameLog>();
+                                log.add_system(copy::REPEAT_NOTHING.to_string());
+                                RunState::AwaitingInput {
+                                    offset_x: *offset_x,
+                                    offset_y: *offset_y,
+                                }
+                            }
+                        },
+                        MapAction::RecordMacro1
+                        | MapAction::RecordMacro2
+                        | MapAction::RecordMacro3 => {
+                            toggle_macro_recording(
+                                &self.world,
+                                &mut self.recording_macro,
+                                &mut self.settings.control_scheme,
+                                action.get_macro_slot().unwrap(),
+                            );
+                            RunState::AwaitingInput {
+                                offset_x: *offset_x,
+                                offset_y: *offset_y,
+                            }
+                        }
+                        MapAction::PlayMacro1 | MapAction::PlayMacro2 | MapAction::PlayMacro3 => {
+                            let slot = action.get_macro_slot().unwrap();
+                            match self.settings.control_scheme.macros[slot].is_empty() {
+                                true => {
+                                    let mut log = self.world.fetch_mut::<GameLog>();
+                                    log.add_system(copy::MACRO_EMPTY.to_string());
+                                }
+                                false => {
+                                    self.queued_action = Some(InteractionType::PlayMacro(slot, 0))
+                                }
+                            }
+                            RunState::AwaitingInput {
+                                offset_x: *offset_x,
+                                offset_y: *offset_y,
+                            }
+                        }
                     },
                     None => {
                         let mut next_state = RunState::AwaitingInput {
                             offset_x: *offset_x,
                             offset_y: *offset_y,
                         };
-                        if let Some(interaction) = self.queued_action {
+                        if self.queued_action == Some(InteractionType::Explore) {
+                            next_state = handle_auto_explore(&mut self.world);
+                            if next_state != RunState::PlayerTurn {
+                                self.queued_action = None;
+                            }
+                        } else if self.queued_action == Some(InteractionType::Rest) {
+                            next_state = handle_rest(&mut self.world);
+                            if next_state != RunState::PlayerTurn {
+                                self.queued_action = None;
+                            }
+                        } else if let Some(interaction) = self.queued_action {
                             let interaction_idx = match interaction {
                                 InteractionType::Douse(e)
                                 | InteractionType::Light(e)
@@ -685,7 +1131,9 @@ impl GameState for State {
                                 | InteractionType::Pickup(e)
                                 | InteractionType::OpenDoor(e)
                                 | InteractionType::CloseDoor(e)
-                                | InteractionType::OpenContainer(e) => {
+                                | InteractionType::OpenContainer(e)
+                                | InteractionType::Eat(e)
+                                | InteractionType::Cook(e) => {
                                     let positions = self.world.read_storage::<Position>();
                                     Some(positions.get(e).unwrap().idx)
                                 }
@@ -730,6 +1178,7 @@ impl GameState for State {
             }
             RunState::PlayerTurn => {
                 ScreenMapGeneric::new(0, 0).draw(ctx, &mut self.world);
+                self.world.fetch_mut::<GameLog>().advance_turn();
                 RunState::MonsterTurn
             }
             RunState::MonsterTurn => {
@@ -748,6 +1197,73 @@ impl GameState for State {
                     },
                 }
             }
+            RunState::LandmarkMenu { highlighted } => {
+                let landmarks = travel::get_known_landmarks(&self.world);
+                let menu_options: Box<[MenuOption<&String>]> = landmarks
+                    .iter()
+                    .enumerate()
+                    .map(|(index, landmark)| {
+                        let state = match *highlighted == index {
+                            true => MenuOptionState::Highlighted,
+                            false => MenuOptionState::Normal,
+                        };
+                        MenuOption::new(&landmark.name, state)
+                    })
+                    .collect();
+                let menu = Menu::new(menu_options, 10);
+                ScreenMapMenu::new(
+                    menu.get_page_at_index(*highlighted),
+                    &format!(
+                        "Travel To  < {}/{} >",
+                        menu.page_number_at_index(*highlighted) + 1,
+                        menu.page_count() + 1
+                    ),
+                    "Escape to Cancel",
+                )
+                .draw(ctx, &mut self.world);
+                match self
+                    .settings
+                    .control_scheme
+                    .menu
+                    .get_value_with_context(ctx)
+                {
+                    Some(action) => match action {
+                        MenuAction::Exit => RunState::AwaitingInput {
+                            offset_x: 0,
+                            offset_y: 0,
+                        },
+                        MenuAction::MoveHighlightNext => RunState::LandmarkMenu {
+                            highlighted: menu.get_next_index(*highlighted),
+                        },
+                        MenuAction::MoveHighlightPrev => RunState::LandmarkMenu {
+                            highlighted: menu.get_previous_index(*highlighted),
+                        },
+                        MenuAction::NextPage => RunState::LandmarkMenu {
+                            highlighted: menu.get_next_page_index(*highlighted),
+                        },
+                        MenuAction::PreviousPage => RunState::LandmarkMenu {
+                            highlighted: menu.get_previous_page_index(*highlighted),
+                        },
+                        MenuAction::Select => {
+                            if let Some(landmark) = landmarks.get(*highlighted) {
+                                if landmark.idx != get_player_idx(&self.world) {
+                                    self.queued_action = Some(InteractionType::Move(landmark.idx));
+                                }
+                            }
+                            RunState::AwaitingInput {
+                                offset_x: 0,
+                                offset_y: 0,
+                            }
+                        }
+                        _ => RunState::LandmarkMenu {
+                            highlighted: *highlighted,
+                        },
+                    },
+                    None => RunState::LandmarkMenu {
+                        highlighted: *highlighted,
+                    },
+                }
+            }
             RunState::InventoryMenu { highlighted } => {
                 let inventory = inventory::get_player_inventory_list(&mut self.world);
                 let (inventory_entities, inventory_names): (Vec<_>, Vec<_>) =
@@ -771,7 +1287,10 @@ impl GameState for State {
                         menu.page_number_at_index(*highlighted) + 1,
                         menu.page_count() + 1
                     ),
-                    "Escape to Cancel",
+                    &format!(
+                        "{}. Escape to Cancel",
+                        inventory::get_player_load_text(&self.world)
+                    ),
                 )
                 .draw(ctx, &mut self.world);
                 match self
@@ -807,7 +1326,11 @@ impl GameState for State {
                                     }
                                 };
                                 match is_ranged {
-                                    Some(range) => RunState::ItemUseTargeting { range, item: *ent },
+                                    Some(range) => RunState::ItemUseTargeting {
+                                        range,
+                                        item: *ent,
+                                        cursor: None,
+                                    },
                                     None => {
                                         player::use_item(&mut self.world, *ent, None);
                                         RunState::PlayerTurn
@@ -850,7 +1373,10 @@ impl GameState for State {
                         menu.page_number_at_index(*highlighted) + 1,
                         menu.page_count() + 1
                     ),
-                    "Escape to Cancel",
+                    &format!(
+                        "{}. A to drop a whole stack. Escape to Cancel",
+                        inventory::get_player_load_text(&self.world)
+                    ),
                 )
                 .draw(ctx, &mut self.world);
                 match self
@@ -878,13 +1404,16 @@ impl GameState for State {
                         },
                         MenuAction::Select => match inventory_entities.get(*highlighted) {
                             Some(ent) => {
-                                let mut intent = self.world.write_storage::<WantsToDropItem>();
-                                intent
-                                    .insert(
-                                        *self.world.fetch::<Entity>(),
-                                        WantsToDropItem { item: *ent },
-                                    )
-                                    .expect("Unable To Insert Drop Item Intent");
+                                player::drop_item(&mut self.world, *ent, false);
+                                RunState::PlayerTurn
+                            }
+                            None => RunState::DropItemMenu {
+                                highlighted: *highlighted,
+                            },
+                        },
+                        MenuAction::SelectAll => match inventory_entities.get(*highlighted) {
+                            Some(ent) => {
+                                player::drop_item(&mut self.world, *ent, true);
                                 RunState::PlayerTurn
                             }
                             None => RunState::DropItemMenu {
@@ -937,7 +1466,7 @@ impl GameState for State {
                         MenuAction::Select => match highlighted {
                             0 => {
                                 persistence::delete_save();
-                                match has_objective_in_backpack(&self.world) {
+                                match inventory::has_objective_in_backpack(&self.world) {
                                     true => RunState::SuccessScreen,
                                     false => RunState::FailureScreen,
                                 }
@@ -956,10 +1485,70 @@ impl GameState for State {
                     },
                 }
             }
-            RunState::ItemUseTargeting { range, item } => {
+            RunState::RepeatMenu {
+                highlighted,
+                action,
+            } => {
+                let menu_options: Box<[MenuOption<&str>]> = REPEAT_OPTIONS
+                    .iter()
+                    .enumerate()
+                    .map(|(index, (text, _))| {
+                        let state = match *highlighted == index {
+                            true => MenuOptionState::Highlighted,
+                            false => MenuOptionState::Normal,
+                        };
+                        MenuOption::new(*text, state)
+                    })
+                    .collect();
+                let menu = Menu::new(menu_options, 10);
+                let title = format!("Repeat {}", action);
+                ScreenMapMenu::new(menu.get_page(0), &title, copy::CTA_REPEAT)
+                    .draw(ctx, &mut self.world);
+                match self
+                    .settings
+                    .control_scheme
+                    .menu
+                    .get_value_with_context(ctx)
+                {
+                    Some(MenuAction::Exit) => RunState::AwaitingInput {
+                        offset_x: 0,
+                        offset_y: 0,
+                    },
+                    Some(MenuAction::MoveHighlightNext) => RunState::RepeatMenu {
+                        highlighted: menu.get_next_page_index(*highlighted),
+                        action: *action,
+                    },
+                    Some(MenuAction::MoveHighlightPrev) => RunState::RepeatMenu {
+                        highlighted: menu.get_previous_page_index(*highlighted),
+                        action: *action,
+                    },
+                    Some(MenuAction::Select) => {
+                        self.queued_action = Some(InteractionType::Repeat(
+                            *action,
+                            REPEAT_OPTIONS[*highlighted].1,
+                        ));
+                        RunState::AwaitingInput {
+                            offset_x: 0,
+                            offset_y: 0,
+                        }
+                    }
+                    _ => RunState::RepeatMenu {
+                        highlighted: *highlighted,
+                        action: *action,
+                    },
+                }
+            }
+            RunState::ItemUseTargeting {
+                range,
+                item,
+                cursor,
+            } => {
                 let visible_tiles = ranged::get_visible_tiles_in_range(&self.world, *range);
-                let target = ranged::get_target(&self.world, ctx, &visible_tiles);
-                ScreenMapTargeting::new(*range, target, Some("Select Target".to_string()))
+                let target = match cursor {
+                    Some(idx) => ranged::get_target_at_cursor(&visible_tiles, *idx),
+                    None => ranged::get_target(&self.world, ctx, &visible_tiles),
+                };
+                ScreenMapTargeting::new(*range, target, *cursor, Some("Select Target".to_string()))
                     .draw(ctx, &mut self.world);
                 match self
                     .settings
@@ -982,10 +1571,25 @@ impl GameState for State {
                                 offset_y: 0,
                             },
                         },
+                        _ => {
+                            let (delta_x, delta_y) = action.get_direction().unwrap_or((0, 0));
+                            let idx = cursor.unwrap_or_else(|| get_player_idx(&self.world));
+                            RunState::ItemUseTargeting {
+                                range: *range,
+                                item: *item,
+                                cursor: Some(move_examine_cursor(
+                                    &self.world,
+                                    idx,
+                                    delta_x,
+                                    delta_y,
+                                )),
+                            }
+                        }
                     },
                     None => RunState::ItemUseTargeting {
                         range: *range,
                         item: *item,
+                        cursor: *cursor,
                     },
                 }
             }
@@ -1048,6 +1652,13 @@ impl GameState for State {
                             offset_y: 0,
                         },
                         MenuAction::Select => match options[*interaction_menu_idx] {
+                            interaction if !idx_is_within_reach(&self.world, *idx) => {
+                                self.queued_action = Some(interaction);
+                                RunState::AwaitingInput {
+                                    offset_x: 0,
+                                    offset_y: 0,
+                                }
+                            }
                             InteractionType::OpenContainer(container) => {
                                 RunState::OpenContainerMenu {
                                     highlighted: 0,
@@ -1146,6 +1757,22 @@ impl GameState for State {
                     },
                 }
             }
+            RunState::ExamineMode { idx } => {
+                ScreenMapExamine::new(*idx).draw(ctx, &mut self.world);
+                match self.settings.control_scheme.map.get_value_with_context(ctx) {
+                    Some(MapAction::Exit) | Some(MapAction::Examine) => RunState::AwaitingInput {
+                        offset_x: 0,
+                        offset_y: 0,
+                    },
+                    Some(action) => match action.get_direction() {
+                        Some((delta_x, delta_y)) => RunState::ExamineMode {
+                            idx: move_examine_cursor(&self.world, *idx, delta_x, delta_y),
+                        },
+                        None => RunState::ExamineMode { idx: *idx },
+                    },
+                    None => RunState::ExamineMode { idx: *idx },
+                }
+            }
             RunState::InteractiveEntityTargeting { target_idx } => {
                 let targets = get_interaction_targets(&self.world);
                 let target_ent = targets.get(*target_idx);
@@ -1719,10 +2346,7 @@ impl GameState for State {
                 {
                     Some(action) => match action {
                         StaticAction::Exit => RunState::MainMenu { highlighted: 0 },
-                        StaticAction::Continue => {
-                            self.music.play_music();
-                            RunState::PreRun
-                        }
+                        StaticAction::Continue => RunState::PreRun,
                     },
                     None => RunState::IntroScreen,
                 }
@@ -1775,6 +2399,72 @@ impl GameState for State {
                     None => RunState::CreditsScreen,
                 }
             }
+            RunState::OverviewScreen => {
+                ScreenMapOverview::new().draw(ctx, &mut self.world);
+                match self
+                    .settings
+                    .control_scheme
+                    .menu
+                    .get_value_with_context(ctx)
+                {
+                    Some(MenuAction::Exit) => RunState::AwaitingInput {
+                        offset_x: 0,
+                        offset_y: 0,
+                    },
+                    _ => RunState::OverviewScreen,
+                }
+            }
+            RunState::MessageLogScreen { offset, filter } => {
+                let log = self.world.fetch::<GameLog>();
+                let entries = log.get_filtered_entries(*filter);
+                let max_offset = entries.len().saturating_sub(MESSAGE_LOG_PAGE_SIZE);
+                ScreenMessageLog::new(&entries, *offset, *filter).draw(ctx);
+                match self
+                    .settings
+                    .control_scheme
+                    .menu
+                    .get_value_with_context(ctx)
+                {
+                    Some(action) => match action {
+                        MenuAction::Exit => RunState::AwaitingInput {
+                            offset_x: 0,
+                            offset_y: 0,
+                        },
+                        MenuAction::MoveHighlightNext => RunState::MessageLogScreen {
+                            offset: (*offset + 1).min(max_offset),
+                            filter: *filter,
+                        },
+                        MenuAction::MoveHighlightPrev => RunState::MessageLogScreen {
+                            offset: offset.saturating_sub(1),
+                            filter: *filter,
+                        },
+                        MenuAction::NextPage => RunState::MessageLogScreen {
+                            offset: (*offset + MESSAGE_LOG_PAGE_SIZE).min(max_offset),
+                            filter: *filter,
+                        },
+                        MenuAction::PreviousPage => RunState::MessageLogScreen {
+                            offset: offset.saturating_sub(MESSAGE_LOG_PAGE_SIZE),
+                            filter: *filter,
+                        },
+                        MenuAction::NextMenu => RunState::MessageLogScreen {
+                            offset: 0,
+                            filter: LogCategory::get_next_filter(*filter),
+                        },
+                        MenuAction::PreviousMenu => RunState::MessageLogScreen {
+                            offset: 0,
+                            filter: LogCategory::get_previous_filter(*filter),
+                        },
+                        _ => RunState::MessageLogScreen {
+                            offset: *offset,
+                            filter: *filter,
+                        },
+                    },
+                    None => RunState::MessageLogScreen {
+                        offset: *offset,
+                        filter: *filter,
+                    },
+                }
+            }
             RunState::SavingScreen { count_down } => {
                 ScreenSaving::new().draw(ctx);
                 match *count_down > 0 {
@@ -1796,7 +2486,6 @@ impl GameState for State {
                     _ => {
                         persistence::load_game(&mut self.world);
                         persistence::delete_save();
-                        self.music.play_music();
                         RunState::AwaitingInput {
                             offset_x: 0,
                             offset_y: 0,
@@ -1819,57 +2508,178 @@ impl GameState for State {
             RunState::SetKey {
                 action,
                 highlighted,
+                conflict,
+            } => {
+                let bindings: Box<[String]> = self
+                    .settings
+                    .control_scheme
+                    .map
+                    .get_controls_for_value(action)
+                    .iter()
+                    .map(|c| c.to_string())
+                    .collect();
+                let conflicts = match conflict {
+                    Some(control) => self.settings.control_scheme.get_conflicts(control, action),
+                    None => vec![],
+                };
+                ScreenSetKey::new(action, &bindings, conflict.as_ref(), &conflicts).draw(ctx);
+                match Control::from_context(ctx) {
+                    _ if ctx.key == Some(rltk::VirtualKeyCode::Escape) => RunState::OptionsScreen {
+                        tab: OptionsTab::Controls,
+                        highlighted: *highlighted,
+                    },
+                    Some(control) => {
+                        let conflicts =
+                            self.settings.control_scheme.get_conflicts(&control, action);
+                        // a conflicting control has to be pressed twice to be bound
+                        match conflicts.is_empty() || *conflict == Some(control) {
+                            true => {
+                                self.settings.control_scheme.map.insert(control, *action);
+                                RunState::OptionsScreen {
+                                    tab: OptionsTab::Controls,
+                                    highlighted: *highlighted,
+                                }
+                            }
+                            false => RunState::SetKey {
+                                action: *action,
+                                highlighted: *highlighted,
+                                conflict: Some(control),
+                            },
+                        }
+                    }
+                    None => RunState::SetKey {
+                        action: *action,
+                        highlighted: *highlighted,
+                        conflict: *conflict,
+                    },
+                }
+            }
+            RunState::ResetOptionsMenu {
+                tab,
+                highlighted,
+                option_highlighted,
             } => {
-                // add screen that prompts for key input
-                ScreenSetKey::new(action).draw(ctx);
-                match ctx.key {
-                    Some(key) => match key {
-                        rltk::VirtualKeyCode::Escape => RunState::OptionsScreen {
+                let reset_text = match tab {
+                    OptionsTab::Controls => "Yes, reset all bindings and macros",
+                    _ => "Yes, reset all options",
+                };
+                let menu_options: Box<[MenuOption<&str>]> = [reset_text, "No, keep them"]
+                    .iter()
+                    .enumerate()
+                    .map(|(index, text)| {
+                        let state = match *option_highlighted == index {
+                            true => MenuOptionState::Highlighted,
+                            false => MenuOptionState::Normal,
+                        };
+                        MenuOption::new(*text, state)
+                    })
+                    .collect();
+                let menu = Menu::new(menu_options, 10);
+                let tab_options: Box<[MenuOption<&str>]> = OptionsTab::tabs()
+                    .iter()
+                    .map(|t| {
+                        MenuOption::new(
+                            t.get_name(),
+                            match t == tab {
+                                true => MenuOptionState::Highlighted,
+                                false => MenuOptionState::Normal,
+                            },
+                        )
+                    })
+                    .collect();
+                ScreenOptions::new(
+                    "Reset To Defaults?",
+                    copy::CTA_RESET_OPTIONS,
+                    tab_options.iter().collect(),
+                    menu.get_page(0),
+                )
+                .draw(ctx);
+                match self
+                    .settings
+                    .control_scheme
+                    .menu
+                    .get_value_with_context(ctx)
+                {
+                    Some(action) => match action {
+                        MenuAction::Exit => RunState::OptionsScreen {
+                            tab: *tab,
                             highlighted: *highlighted,
                         },
-                        rltk::VirtualKeyCode::LShift
-                        | rltk::VirtualKeyCode::RShift
-                        | rltk::VirtualKeyCode::LControl
-                        | rltk::VirtualKeyCode::RControl => RunState::SetKey {
-                            action: *action,
+                        MenuAction::MoveHighlightNext => RunState::ResetOptionsMenu {
+                            tab: *tab,
                             highlighted: *highlighted,
+                            option_highlighted: menu.get_next_page_index(*option_highlighted),
                         },
-                        _ => {
-                            //set the key
-                            self.settings.control_scheme.map.remove_by_value(action);
-                            self.settings
-                                .control_scheme
-                                .map
-                                .insert_with_context(ctx, *action);
+                        MenuAction::MoveHighlightPrev => RunState::ResetOptionsMenu {
+                            tab: *tab,
+                            highlighted: *highlighted,
+                            option_highlighted: menu.get_previous_page_index(*option_highlighted),
+                        },
+                        MenuAction::Select => {
+                            if *option_highlighted == 0 {
+                                match tab {
+                                    OptionsTab::Controls => {
+                                        self.settings.control_scheme = ControlScheme::default()
+                                    }
+                                    _ => {
+                                        self.settings.preferences = Preferences::default();
+                                        apply_preferences(
+                                            &self.settings.preferences,
+                                            &mut self.world,
+                                            self.audio.as_mut(),
+                                        );
+                                    }
+                                }
+                            }
                             RunState::OptionsScreen {
+                                tab: *tab,
                                 highlighted: *highlighted,
                             }
                         }
+                        _ => RunState::ResetOptionsMenu {
+                            tab: *tab,
+                            highlighted: *highlighted,
+                            option_highlighted: *option_highlighted,
+                        },
                     },
-                    None => RunState::SetKey {
-                        action: *action,
+                    None => RunState::ResetOptionsMenu {
+                        tab: *tab,
                         highlighted: *highlighted,
+                        option_highlighted: *option_highlighted,
                     },
                 }
             }
-            RunState::OptionsScreen { highlighted } => {
-                let map_controls = &self.settings.control_scheme.map;
-                let menu_option_text: Box<[String]> = MapAction::actions()
-                    .iter()
-                    .map(|map_action| {
-                        let control_text = match map_controls.get_control_for_value(map_action) {
-                            Some(c) => c.to_string(),
-                            None => String::from("None"),
-                        };
-                        let action_text = map_action.to_string();
-                        let space_count = 50 - (control_text.len() + action_text.len());
-                        let space_text = match space_count > 0 {
-                            true => (0..space_count).map(|_| " ").collect(),
-                            false => String::from(""),
-                        };
-                        format!("{}{}{}", action_text, space_text, control_text)
-                    })
-                    .collect();
+            RunState::OptionsScreen { tab, highlighted } => {
+                let menu_option_text: Box<[String]> = match tab {
+                    OptionsTab::Controls => {
+                        let map_controls = &self.settings.control_scheme.map;
+                        MapAction::actions()
+                            .iter()
+                            .map(|map_action| {
+                                let controls = map_controls.get_controls_for_value(map_action);
+                                let control_text = match controls.is_empty() {
+                                    true => String::from("None"),
+                                    false => controls
+                                        .iter()
+                                        .map(|c| c.to_string())
+                                        .collect::<Vec<String>>()
+                                        .join(", "),
+                                };
+                                get_options_row_text(&map_action.to_string(), &control_text)
+                            })
+                            .collect()
+                    }
+                    _ => tab
+                        .get_options()
+                        .iter()
+                        .map(|option| {
+                            get_options_row_text(
+                                option.get_label(),
+                                &option.get_value(&self.settings.preferences),
+                            )
+                        })
+                        .collect(),
+                };
                 let menu_options = menu_option_text
                     .iter()
                     .enumerate()
@@ -1883,11 +2693,28 @@ impl GameState for State {
                         )
                     })
                     .collect();
-                let controls_menu = Menu::new(menu_options, MapAction::actions().len());
+                let options_menu = Menu::new(menu_options, OPTIONS_PER_PAGE);
+                let tab_options: Box<[MenuOption<&str>]> = OptionsTab::tabs()
+                    .iter()
+                    .map(|t| {
+                        MenuOption::new(
+                            t.get_name(),
+                            match t == tab {
+                                true => MenuOptionState::Highlighted,
+                                false => MenuOptionState::Normal,
+                            },
+                        )
+                    })
+                    .collect();
+                let cta = match tab {
+                    OptionsTab::Controls => copy::CTA_OPTIONS_CONTROLS,
+                    _ => copy::CTA_OPTIONS,
+                };
                 ScreenOptions::new(
                     "Options",
-                    "Press Enter to set control, Delete to clear",
-                    controls_menu.get_page_at_index(*highlighted),
+                    cta,
+                    tab_options.iter().collect(),
+                    options_menu.get_page_at_index(*highlighted),
                 )
                 .draw(ctx);
                 match self
@@ -1901,31 +2728,67 @@ impl GameState for State {
                             self.settings.save();
                             RunState::MainMenu { highlighted: 0 }
                         }
-                        MenuAction::Select => RunState::SetKey {
-                            action: MapAction::actions()[*highlighted],
-                            highlighted: *highlighted,
+                        MenuAction::NextMenu => RunState::OptionsScreen {
+                            tab: tab.get_next(),
+                            highlighted: 0,
+                        },
+                        MenuAction::PreviousMenu => RunState::OptionsScreen {
+                            tab: tab.get_previous(),
+                            highlighted: 0,
+                        },
+                        MenuAction::Select => match tab {
+                            OptionsTab::Controls => RunState::SetKey {
+                                action: MapAction::actions()[*highlighted],
+                                highlighted: *highlighted,
+                                conflict: None,
+                            },
+                            _ => {
+                                tab.get_options()[*highlighted]
+                                    .change(&mut self.settings.preferences);
+                                apply_preferences(
+                                    &self.settings.preferences,
+                                    &mut self.world,
+                                    self.audio.as_mut(),
+                                );
+                                RunState::OptionsScreen {
+                                    tab: *tab,
+                                    highlighted: *highlighted,
+                                }
+                            }
                         },
                         MenuAction::Delete => {
-                            let highlighted_value = MapAction::actions()[*highlighted];
-                            self.settings
-                                .control_scheme
-                                .map
-                                .remove_by_value(&highlighted_value);
+                            if *tab == OptionsTab::Controls {
+                                let highlighted_value = MapAction::actions()[*highlighted];
+                                self.settings
+                                    .control_scheme
+                                    .map
+                                    .remove_by_value(&highlighted_value);
+                            }
                             RunState::OptionsScreen {
+                                tab: *tab,
                                 highlighted: *highlighted,
                             }
                         }
+                        MenuAction::Reset => RunState::ResetOptionsMenu {
+                            tab: *tab,
+                            highlighted: *highlighted,
+                            option_highlighted: 0,
+                        },
                         MenuAction::MoveHighlightNext => RunState::OptionsScreen {
-                            highlighted: controls_menu.get_next_index(*highlighted),
+                            tab: *tab,
+                            highlighted: options_menu.get_next_index(*highlighted),
                         },
                         MenuAction::MoveHighlightPrev => RunState::OptionsScreen {
-                            highlighted: controls_menu.get_previous_index(*highlighted),
+                            tab: *tab,
+                            highlighted: options_menu.get_previous_index(*highlighted),
                         },
                         _ => RunState::OptionsScreen {
+                            tab: *tab,
                             highlighted: *highlighted,
                         },
                     },
                     None => RunState::OptionsScreen {
+                        tab: *tab,
                         highlighted: *highlighted,
                     },
                 }
@@ -1998,7 +2861,10 @@ impl GameState for State {
                         MenuAction::Select => match *highlighted {
                             0 => RunState::NewGameScreen { count_down: 15 },
                             1 => RunState::LoadingScreen { count_down: 15 },
-                            2 => RunState::OptionsScreen { highlighted: 0 },
+                            2 => RunState::OptionsScreen {
+                                tab: OptionsTab::Display,
+                                highlighted: 0,
+                            },
                             3 => RunState::CreditsScreen,
                             4 => std::process::exit(0),
                             _ => RunState::MainMenu {
@@ -2060,8 +2926,7 @@ impl GameState for State {
                                 debug::kill_all_monsters(&mut self.world);
                                 self.world
                                     .fetch_mut::<GameLog>()
-                                    .entries
-                                    .insert(0, "all monsters removed".to_owned());
+                                    .add_system("all monsters removed".to_owned());
                                 RunState::AwaitingInput {
                                     offset_x: 0,
                                     offset_y: 0,
@@ -2071,8 +2936,7 @@ impl GameState for State {
                                 debug::reveal_map(&mut self.world);
                                 self.world
                                     .fetch_mut::<GameLog>()
-                                    .entries
-                                    .insert(0, "map revealed".to_owned());
+                                    .add_system("map revealed".to_owned());
                                 RunState::AwaitingInput {
                                     offset_x: 0,
                                     offset_y: 0,
@@ -114,7 +114,7 @@ impl State for MonsterState {
             })
             .collect();
         render_data.sort_unstable_by(|a, b| b.layer.cmp(&a.layer));
-        let log_entries = log.entries.iter().map(String::as_str).collect();
+        let log_entries = log.entries.iter().collect();
         UIMapScreen::new(
             mouse_x,
             mouse_y,
@@ -0,0 +1,85 @@
+use crate::components::{CausesLight, Cookable, Edible, Inventory, Name, Stackable, WantsToCook};
+use crate::inventory;
+use crate::services::GameLog;
+use specs::{Entities, Entity, Join, ReadExpect, ReadStorage, System, WriteExpect, WriteStorage};
+
+pub struct CookSystem {}
+
+impl<'a> System<'a> for CookSystem {
+    type SystemData = (
+        Entities<'a>,
+        ReadExpect<'a, Entity>,
+        WriteExpect<'a, GameLog>,
+        WriteStorage<'a, WantsToCook>,
+        ReadStorage<'a, CausesLight>,
+        WriteStorage<'a, Inventory>,
+        WriteStorage<'a, Cookable>,
+        WriteStorage<'a, Edible>,
+        WriteStorage<'a, Name>,
+        WriteStorage<'a, Stackable>,
+    );
+
+    fn run(&mut self, data: Self::SystemData) {
+        let (
+            entities,
+            player_entity,
+            mut game_log,
+            mut wants_to_cook,
+            causes_light,
+            mut inventories,
+            mut cookables,
+            mut edibles,
+            mut names,
+            mut stackables,
+        ) = data;
+        for (intent, entity, inventory) in (&wants_to_cook, &entities, &mut inventories).join() {
+            let is_player = entity == *player_entity;
+            let lit = causes_light
+                .get(intent.stove)
+                .map_or(false, |light| light.lit);
+            if !lit {
+                if is_player {
+                    game_log.add_system("The stove must be lit to cook on it".to_string());
+                }
+                continue;
+            }
+            let raw_food: Vec<Entity> = inventory
+                .items
+                .iter()
+                .filter(|item| cookables.get(**item).is_some())
+                .copied()
+                .collect();
+            if raw_food.is_empty() && is_player {
+                game_log.add_system("You have nothing to cook".to_string());
+            }
+            for food in raw_food {
+                let cookable = cookables.remove(food).unwrap();
+                if let Some(edible) = edibles.get_mut(food) {
+                    edible.nutrition = cookable.cooked_nutrition;
+                }
+                if let Some(name) = names.get_mut(food) {
+                    if is_player {
+                        game_log.add_system(format!("You cook the {}", name.name));
+                    }
+                    name.name = cookable.cooked_name;
+                }
+                // cooked food joins any of the same food that was already cooked
+                let stack = inventory::find_matching_stack(
+                    inventory.items.iter(),
+                    food,
+                    &names,
+                    &stackables,
+                );
+                if let Some(stack) = stack {
+                    let quantity = stackables.get(food).unwrap().quantity;
+                    stackables.get_mut(stack).unwrap().quantity += quantity;
+                    inventory.items.remove(&food);
+                    entities
+                        .delete(food)
+                        .expect("could not delete food merged into a stack");
+                }
+            }
+        }
+        wants_to_cook.clear();
+    }
+}
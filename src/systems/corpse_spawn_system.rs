@@ -1,4 +1,4 @@
-use crate::components::{Container, Name, Position, Renderable, Saveable};
+use crate::components::{Container, Edible, Name, Position, Renderable, Rotting, Saveable};
 use crate::services::CorpseSpawner;
 use specs::{
     saveload::{MarkerAllocator, SimpleMarker, SimpleMarkerAllocator},
@@ -13,6 +13,8 @@ impl<'a> System<'a> for CorpseSpawnSystem {
         WriteStorage<'a, Renderable>,
         WriteStorage<'a, Name>,
         WriteStorage<'a, Container>,
+        WriteStorage<'a, Edible>,
+        WriteStorage<'a, Rotting>,
         WriteExpect<'a, CorpseSpawner>,
         WriteExpect<'a, SimpleMarkerAllocator<Saveable>>,
         WriteStorage<'a, SimpleMarker<Saveable>>,
@@ -25,6 +27,8 @@ impl<'a> System<'a> for CorpseSpawnSystem {
             mut renderables,
             mut names,
             mut containers,
+            mut edibles,
+            mut rottings,
             mut spawner,
             mut marker_allocator,
             mut markers,
@@ -67,6 +71,14 @@ impl<'a> System<'a> for CorpseSpawnSystem {
                     },
                 )
                 .expect("failed inserting container for corpse");
+            if let Some(nutrition) = request.nutrition {
+                edibles
+                    .insert(new_corpse, Edible { nutrition })
+                    .expect("failed inserting edible for corpse");
+                rottings
+                    .insert(new_corpse, Rotting { age: 0 })
+                    .expect("failed inserting rotting for corpse");
+            }
             marker_allocator.mark(new_corpse, &mut markers);
         }
     }
@@ -63,7 +63,7 @@ impl<'a> DamageSystem<'a> {
                         }
                     }
                     if visible_to_player {
-                        log.add(format!("{} has died", name.name));
+                        log.add_combat(format!("{} has died", name.name));
                     }
                 } else {
                     let name = names.get(entity).unwrap();
@@ -77,7 +77,7 @@ impl<'a> DamageSystem<'a> {
                         true,
                     );
                     if visible_to_player {
-                        log.add(format!("{} has been destroyed", name.name));
+                        log.add_combat(format!("{} has been destroyed", name.name));
                     }
                     if let Some(container) = containers.get(entity) {
                         container.items.iter().for_each(|e| {
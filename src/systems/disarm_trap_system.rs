@@ -38,13 +38,13 @@ impl<'a> System<'a> for DisarmTrapSystem {
             let item_name = item_type::get_name_for_item(&item_type);
             match rng.range(0, 6) {
                 0 => {
-                    log.add(format!("You failed to disarm the {}.", item_name));
+                    log.add_system(format!("You failed to disarm the {}.", item_name));
                 }
                 _ => {
                     let position = positions.get(intent.trap).unwrap();
                     spawner.request(position.idx, position.level, item_type);
                     if *player_entity == entity {
-                        log.add(format!("You disarmed the {}.", item_name));
+                        log.add_system(format!("You disarmed the {}.", item_name));
                     }
                     entities
                         .delete(intent.trap)
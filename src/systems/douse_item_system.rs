@@ -37,7 +37,7 @@ impl<'a> System<'a> for DouseItemSystem {
                         _ => "unknown".to_string(),
                     };
                     if entity == *player_entity {
-                        game_log.add(format!("you douse the {}", item_name));
+                        game_log.add_system(format!("you douse the {}", item_name));
                     }
                 }
             }
@@ -0,0 +1,99 @@
+use crate::components::{
+    causes_damage::DamageType, hunger::MAX_NUTRITION, Container, DamageHistory, Edible, Hunger,
+    Inventory, Name, Position, Rotting, Stackable, SufferDamage, WantsToEat,
+};
+use crate::inventory;
+use crate::services::GameLog;
+use specs::{
+    storage::GenericWriteStorage, Entities, Entity, Join, ReadExpect, ReadStorage, System,
+    WriteExpect, WriteStorage,
+};
+
+pub struct EatSystem {}
+
+impl<'a> System<'a> for EatSystem {
+    type SystemData = (
+        Entities<'a>,
+        ReadExpect<'a, Entity>,
+        WriteExpect<'a, GameLog>,
+        WriteStorage<'a, WantsToEat>,
+        WriteStorage<'a, Hunger>,
+        ReadStorage<'a, Edible>,
+        ReadStorage<'a, Rotting>,
+        ReadStorage<'a, Name>,
+        WriteStorage<'a, Inventory>,
+        ReadStorage<'a, Container>,
+        WriteStorage<'a, Position>,
+        WriteStorage<'a, SufferDamage>,
+        WriteStorage<'a, DamageHistory>,
+        WriteStorage<'a, Stackable>,
+    );
+
+    fn run(&mut self, data: Self::SystemData) {
+        let (
+            entities,
+            player_entity,
+            mut log,
+            mut wants_to_eat,
+            mut hungers,
+            edibles,
+            rotting,
+            names,
+            mut inventories,
+            containers,
+            mut positions,
+            mut suffer_damage,
+            mut damage_histories,
+            mut stackables,
+        ) = data;
+        for (intent, entity, hunger) in (&wants_to_eat, &entities, &mut hungers).join() {
+            let edible = match edibles.get(intent.food) {
+                Some(edible) => edible,
+                None => continue,
+            };
+            let rot = rotting.get(intent.food);
+            let nutrition = rot.map_or(edible.nutrition, |rot| rot.get_nutrition(edible.nutrition));
+            hunger.nutrition = (hunger.nutrition.max(0) + nutrition).min(MAX_NUTRITION);
+            if let Some(rot) = rot {
+                if let Some(damage_to_suffer) = suffer_damage.get_mut_or_default(entity) {
+                    damage_to_suffer.amount += rot.get_damage();
+                }
+                if let Some(damage_history) = damage_histories.get_mut(entity) {
+                    damage_history.events.insert(DamageType::Poison);
+                }
+            }
+            if entity == *player_entity {
+                let food_name = match names.get(intent.food) {
+                    Some(name) => name.name.clone(),
+                    None => "unknown".to_string(),
+                };
+                match rot {
+                    Some(_) => {
+                        log.add_system(format!("You eat the {}, it makes you sick", food_name))
+                    }
+                    None => log.add_system(format!("You eat the {}", food_name)),
+                }
+            }
+            if inventory::take_one_from_stack(&mut stackables, intent.food) {
+                continue;
+            }
+            if let Some(inventory) = inventories.get_mut(entity) {
+                inventory.items.remove(&intent.food);
+            }
+            // whatever a corpse was carrying is left behind once it is eaten
+            if let Some(container) = containers.get(intent.food) {
+                if let Some(position) = positions.get(intent.food).cloned() {
+                    for item in container.items.iter() {
+                        positions
+                            .insert(*item, position.clone())
+                            .expect("could not drop item from eaten corpse");
+                    }
+                }
+            }
+            entities
+                .delete(intent.food)
+                .expect("could not delete eaten food");
+        }
+        wants_to_eat.clear();
+    }
+}
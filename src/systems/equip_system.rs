@@ -63,14 +63,14 @@ impl<'a> System<'a> for EquipSystem {
                 inventory.items.insert(old_equipment_ent);
                 if entity == *player_entity {
                     let name = &names.get(old_equipment_ent).unwrap().name;
-                    log.add(format!("You unequip the {}", name));
+                    log.add_system(format!("You unequip the {}", name));
                 }
             }
             if let Some(equipment_ent) = intent.equipment {
                 inventory.items.remove(&equipment_ent);
                 if entity == *player_entity {
                     let name = &names.get(equipment_ent).unwrap().name;
-                    log.add(format!("You equip the {}", name));
+                    log.add_system(format!("You equip the {}", name));
                 }
             }
             set_at_position_in_equipment(ent_equipment, intent.equipment, intent.position);
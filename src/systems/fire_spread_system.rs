@@ -1,5 +1,7 @@
+use crate::audio::SoundEffect;
 use crate::components::{CausesLight, CombatStats, DamageHistory, Flammable, OnFire, Position, SufferDamage, causes_damage::DamageType};
 use crate::dungeon::{dungeon::Dungeon, level_utils};
+use crate::services::SoundEffectQueue;
 use rltk::RandomNumberGenerator;
 use specs::{
     storage::GenericWriteStorage, Entity, Join, ReadExpect, ReadStorage, System, WriteExpect,
@@ -18,7 +20,8 @@ impl<'a> System<'a> for FireSpreadSystem {
         WriteStorage<'a, SufferDamage>,
         WriteExpect<'a, RandomNumberGenerator>,
         WriteStorage<'a, CausesLight>,
-        WriteStorage<'a, DamageHistory>
+        WriteStorage<'a, DamageHistory>,
+        WriteExpect<'a, SoundEffectQueue>,
     );
 
     fn run(&mut self, data: Self::SystemData) {
@@ -31,7 +34,8 @@ impl<'a> System<'a> for FireSpreadSystem {
             mut suffer_damage,
             mut rng,
             mut causes_light,
-            mut damage_histories
+            mut damage_histories,
+            mut sound_effects,
         ) = data;
 
         let affected_entities: Vec<Entity> = (&mut on_fires, &positions)
@@ -52,6 +56,9 @@ impl<'a> System<'a> for FireSpreadSystem {
                     on_fires
                         .insert(*e, OnFire {})
                         .expect("couldn't light entity on fire");
+                    if let Some(position) = positions.get(*e) {
+                        sound_effects.request(SoundEffect::Fire, position.idx, position.level);
+                    }
                     causes_light
                         .insert(
                             *e,
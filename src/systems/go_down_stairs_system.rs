@@ -1,7 +1,7 @@
 use crate::dungeon::dungeon::Dungeon;
 use crate::{
     components::{Position, Viewshed, WantsToGoDownStairs},
-    dungeon::level_utils,
+    dungeon::{level_id::LevelId, level_utils},
 };
 use specs::{Entities, Join, ReadExpect, System, WriteStorage};
 pub struct GoDownStairsSystem {}
@@ -22,8 +22,12 @@ impl<'a> System<'a> for GoDownStairsSystem {
         {
             let level = dungeon.get_level(position.level).unwrap();
             if level_utils::idxs_are_adjacent(level.width, position.idx, intent.idx) {
-                if let Some(next_level) = dungeon.get_level(position.level - 1) {
-                    position.level = position.level - 1;
+                let next_level_id = match level.get_branch_at_idx(intent.idx) {
+                    Some(branch) => Some(LevelId::new(branch, branch.get_top_depth())),
+                    None => position.level.get_level_below(),
+                };
+                if let Some(next_level) = next_level_id.and_then(|id| dungeon.get_level(id)) {
+                    position.level = next_level.id;
                     position.idx = next_level.stairs_up.unwrap();
                     let mut viewshed = viewsheds.get_mut(entity).unwrap();
                     viewshed.dirty = true;
@@ -22,9 +22,14 @@ impl<'a> System<'a> for GoUpStairsSystem {
         {
             let level = dungeon.get_level(position.level).unwrap();
             if level_utils::idxs_are_adjacent(level.width, position.idx, intent.idx) {
-                if let Some(next_level) = dungeon.get_level(position.level + 1) {
-                    position.level = position.level + 1;
-                    position.idx = next_level.stairs_down.unwrap();
+                let next_level_id = position.level.get_level_above();
+                if let Some(next_level) = next_level_id.and_then(|id| dungeon.get_level(id)) {
+                    // leaving the top of a side branch comes out at the stairs that lead into it
+                    position.idx = match next_level.id.branch == position.level.branch {
+                        true => next_level.stairs_down.unwrap(),
+                        false => next_level.get_branch_stairs(position.level.branch).unwrap(),
+                    };
+                    position.level = next_level.id;
                     let mut viewshed = viewsheds.get_mut(entity).unwrap();
                     viewshed.dirty = true;
                 }
@@ -27,7 +27,7 @@ impl<'a> System<'a> for GrabSystem {
                             Some(name) => name.name.to_owned(),
                             None => "unknown".to_string(),
                         };
-                        log.add(format!("You grab {}", name))
+                        log.add_system(format!("You grab {}", name))
                     }
                 }
                 None => {
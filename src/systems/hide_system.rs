@@ -44,7 +44,7 @@ impl<'a> System<'a> for HideSystem {
                             Some(name) => name.name.to_owned(),
                             None => "unknown".to_string(),
                         };
-                        log.add(format!("You hide in the {}", name))
+                        log.add_system(format!("You hide in the {}", name))
                     }
                 }
                 None => {
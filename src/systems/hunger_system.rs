@@ -0,0 +1,53 @@
+use crate::components::{
+    causes_damage::DamageType, hunger::HUNGRY_NUTRITION, DamageHistory, Hunger, SufferDamage,
+};
+use crate::services::GameLog;
+use specs::{
+    storage::GenericWriteStorage, Entities, Entity, Join, ReadExpect, System, WriteExpect,
+    WriteStorage,
+};
+
+// turns between each point of damage while starving
+const STARVING_DAMAGE_TURNS: i32 = 10;
+
+pub struct HungerSystem {}
+
+impl<'a> System<'a> for HungerSystem {
+    type SystemData = (
+        Entities<'a>,
+        ReadExpect<'a, Entity>,
+        WriteExpect<'a, GameLog>,
+        WriteStorage<'a, Hunger>,
+        WriteStorage<'a, SufferDamage>,
+        WriteStorage<'a, DamageHistory>,
+    );
+
+    fn run(&mut self, data: Self::SystemData) {
+        let (
+            entities,
+            player_entity,
+            mut log,
+            mut hungers,
+            mut suffer_damage,
+            mut damage_histories,
+        ) = data;
+        for (entity, hunger) in (&entities, &mut hungers).join() {
+            hunger.nutrition -= 1;
+            if entity == *player_entity {
+                if hunger.nutrition == HUNGRY_NUTRITION {
+                    log.add_system("You are getting hungry".to_string());
+                } else if hunger.nutrition == 0 {
+                    log.add_system("You are starving!".to_string());
+                }
+            }
+            if hunger.nutrition <= 0 && hunger.nutrition % STARVING_DAMAGE_TURNS == 0 {
+                if let Some(damage_to_suffer) = suffer_damage.get_mut_or_default(entity) {
+                    damage_to_suffer.amount += 1;
+                }
+                if let Some(damage_history) = damage_histories.get_mut(entity) {
+                    damage_history.events.insert(DamageType::Starve);
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,148 @@
+use crate::components::{Identifiable, Identifies, Info, Inventory, Name, Renderable, WantsToUse};
+use crate::services::{identification, GameLog, Identification};
+use rltk::RandomNumberGenerator;
+use specs::{Entities, Entity, Join, ReadExpect, ReadStorage, System, WriteExpect, WriteStorage};
+
+fn get_unidentified_items(
+    inventory: Option<&Inventory>,
+    scroll: Entity,
+    identifiables: &WriteStorage<Identifiable>,
+) -> Vec<Entity> {
+    match inventory {
+        Some(inventory) => inventory
+            .items
+            .iter()
+            .filter(|item| **item != scroll && identifiables.get(**item).is_some())
+            .copied()
+            .collect(),
+        None => Vec::new(),
+    }
+}
+
+// runs before items are used so using an item identifies it and the use reads with its real name
+pub struct IdentifySystem {}
+
+impl<'a> System<'a> for IdentifySystem {
+    type SystemData = (
+        Entities<'a>,
+        ReadExpect<'a, Entity>,
+        WriteExpect<'a, GameLog>,
+        WriteExpect<'a, Identification>,
+        WriteExpect<'a, RandomNumberGenerator>,
+        ReadStorage<'a, WantsToUse>,
+        ReadStorage<'a, Identifies>,
+        ReadStorage<'a, Inventory>,
+        WriteStorage<'a, Identifiable>,
+        WriteStorage<'a, Name>,
+        WriteStorage<'a, Info>,
+        WriteStorage<'a, Renderable>,
+    );
+
+    fn run(&mut self, data: Self::SystemData) {
+        let (
+            entities,
+            player_entity,
+            mut game_log,
+            mut identification,
+            mut rng,
+            wants_to_use,
+            identifies,
+            inventories,
+            mut identifiables,
+            mut names,
+            mut infos,
+            mut renderables,
+        ) = data;
+        if let Some(to_use) = wants_to_use.get(*player_entity) {
+            let mut to_identify = Vec::new();
+            if let Some(identifiable) = identifiables.get(to_use.item) {
+                to_identify.push(to_use.item);
+                if let Some(name) = names.get(to_use.item) {
+                    game_log.add_system(format!(
+                        "The {} turns out to be a {}",
+                        name.name, identifiable.name
+                    ));
+                }
+            }
+            if identifies.get(to_use.item).is_some() {
+                let unidentified = get_unidentified_items(
+                    inventories.get(*player_entity),
+                    to_use.item,
+                    &identifiables,
+                );
+                match rng.random_slice_entry(&unidentified) {
+                    Some(item) => {
+                        let identifiable = identifiables.get(*item).unwrap();
+                        let appearance = identification.get_appearance(
+                            identifiable.kind,
+                            &identifiable.name,
+                            &mut rng,
+                        );
+                        game_log.add_system(format!(
+                            "The scroll reveals the {} to be a {}",
+                            appearance, identifiable.name
+                        ));
+                        to_identify.push(*item);
+                    }
+                    None => game_log.add_system(
+                        "The scroll glows but you have nothing to identify".to_string(),
+                    ),
+                }
+            }
+            for item in to_identify {
+                let name = identifiables.get(item).unwrap().name.clone();
+                identification.identify(&name);
+            }
+        }
+
+        // items spawn under their real name, so one still going by it hasn't been disguised yet
+        let mut identified = Vec::new();
+        for (entity, identifiable, name) in (&entities, &identifiables, &mut names).join() {
+            let is_disguised = name.name != identifiable.name;
+            match identification.is_identified(&identifiable.name) {
+                true => {
+                    if is_disguised {
+                        name.name = identifiable.name.clone();
+                        infos
+                            .insert(
+                                entity,
+                                Info {
+                                    description: identifiable.description.clone(),
+                                },
+                            )
+                            .expect("could not insert info for identified item");
+                    }
+                    identified.push(entity);
+                }
+                false if !is_disguised => {
+                    let appearance = identification.get_appearance(
+                        identifiable.kind,
+                        &identifiable.name,
+                        &mut rng,
+                    );
+                    // the colour is part of how the item looks so it stays once identified
+                    if let Some(renderable) = renderables.get_mut(entity) {
+                        renderable.fg =
+                            identification::get_appearance_colour(identifiable.kind, &appearance);
+                    }
+                    name.name = appearance;
+                    infos
+                        .insert(
+                            entity,
+                            Info {
+                                description: identification::get_unidentified_description(
+                                    identifiable.kind,
+                                )
+                                .to_string(),
+                            },
+                        )
+                        .expect("could not insert info for unidentified item");
+                }
+                false => {}
+            }
+        }
+        for entity in identified {
+            identifiables.remove(entity);
+        }
+    }
+}
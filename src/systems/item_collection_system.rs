@@ -1,4 +1,8 @@
-use crate::components::{Container, Inventory, Name, Position, WantsToPickUpItem};
+use crate::components::{
+    Bulk, Capacity, Container, Equipment, Inventory, Name, Position, Stackable, WantsToPickUpItem,
+};
+use crate::entity_set::EntitySet;
+use crate::inventory;
 use crate::services::GameLog;
 use specs::{Entities, Entity, Join, ReadExpect, ReadStorage, System, WriteExpect, WriteStorage};
 
@@ -13,7 +17,11 @@ impl<'a> System<'a> for ItemCollectionSystem {
         WriteStorage<'a, Position>,
         ReadStorage<'a, Name>,
         WriteStorage<'a, Container>,
-        WriteStorage<'a, Inventory>
+        WriteStorage<'a, Inventory>,
+        ReadStorage<'a, Bulk>,
+        ReadStorage<'a, Capacity>,
+        WriteStorage<'a, Stackable>,
+        ReadStorage<'a, Equipment>,
     );
 
     fn run(&mut self, data: Self::SystemData) {
@@ -26,22 +34,66 @@ impl<'a> System<'a> for ItemCollectionSystem {
             names,
             mut containers,
             mut inventories,
+            bulks,
+            capacities,
+            mut stackables,
+            equipment,
         ) = data;
 
         for (ent, pick_up, inventory) in (&entities, &wants_to_pick_up, &mut inventories).join() {
+            let is_player = ent == *player_entity;
+            let capacity = capacities.get(ent);
+            // equipped items are still being carried
+            let equipped = equipment
+                .get(ent)
+                .map_or(EntitySet::new(), |equipment| equipment.as_items());
+            let mut held = inventory::get_bulk_of_items(
+                inventory.items.iter().chain(equipped.iter()),
+                &bulks,
+                &stackables,
+            );
             for item in pick_up.items.iter() {
+                let item_name = &names.get(*item).unwrap().name;
+                let item_bulk = inventory::get_bulk_of_item(*item, &bulks, &stackables);
+                if let Some(capacity) = capacity {
+                    if !capacity.fits(&held, &item_bulk) {
+                        if is_player {
+                            game_log.add_system(match capacity.fits_weight(&held, &item_bulk) {
+                                true => format!("you have no room for the {}", item_name),
+                                false => format!("the {} is too heavy to carry", item_name),
+                            });
+                        }
+                        continue;
+                    }
+                }
+                held.weight += item_bulk.weight;
+                held.volume += item_bulk.volume;
                 positions.remove(*item);
                 if let Some(container_ent) = pick_up.container {
                     if let Some(container) = containers.get_mut(container_ent) {
-                      container.items.remove(&item);
+                        container.items.remove(&item);
+                    }
+                }
+                let stack = inventory::find_matching_stack(
+                    inventory.items.iter(),
+                    *item,
+                    &names,
+                    &stackables,
+                );
+                match stack {
+                    Some(stack) => {
+                        let quantity = stackables.get(*item).unwrap().quantity;
+                        stackables.get_mut(stack).unwrap().quantity += quantity;
+                        entities
+                            .delete(*item)
+                            .expect("could not delete item merged into a stack");
+                    }
+                    None => {
+                        inventory.items.insert(*item);
                     }
                 }
-                inventory.items.insert(*item);
-                if ent == *player_entity {
-                    game_log.add(format!(
-                        "you pick up the {}",
-                        names.get(*item).unwrap().name
-                    ))
+                if is_player {
+                    game_log.add_system(format!("you pick up the {}", item_name))
                 }
             }
         }
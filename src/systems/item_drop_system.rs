@@ -43,10 +43,10 @@ impl<'a> System<'a> for ItemDropSystem {
                 .expect("failed to add dropped_ent to positions");
             inventory.items.remove(&dropped_ent);
             if dropping_ent == *player_entity {
-                game_log.entries.insert(
-                    0,
-                    format!("You drop the {}.", names.get(dropped_ent).unwrap().name),
-                )
+                game_log.add_system(format!(
+                    "You drop the {}.",
+                    names.get(dropped_ent).unwrap().name
+                ))
             }
         }
         wants_to_drop.clear();
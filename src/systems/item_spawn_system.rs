@@ -1,5 +1,5 @@
 use crate::components::{
-    Armable, Consumable, Item, Name, Position, Ranged, Renderable, Saveable, Trap,
+    Armable, Bulk, Consumable, Item, Name, Position, Ranged, Renderable, Saveable, Stackable, Trap,
 };
 use crate::services::ItemSpawner;
 use crate::types::item_type;
@@ -21,6 +21,8 @@ impl<'a> System<'a> for ItemSpawnSystem {
         WriteStorage<'a, Ranged>,
         WriteStorage<'a, Trap>,
         WriteStorage<'a, Armable>,
+        WriteStorage<'a, Bulk>,
+        WriteStorage<'a, Stackable>,
         WriteExpect<'a, ItemSpawner>,
         WriteExpect<'a, SimpleMarkerAllocator<Saveable>>,
         WriteStorage<'a, SimpleMarker<Saveable>>,
@@ -37,6 +39,8 @@ impl<'a> System<'a> for ItemSpawnSystem {
             mut ranged,
             mut traps,
             mut armables,
+            mut bulks,
+            mut stackables,
             mut spawner,
             mut marker_allocator,
             mut markers,
@@ -74,6 +78,20 @@ impl<'a> System<'a> for ItemSpawnSystem {
             items
                 .insert(new_item, Item {})
                 .expect("failed inserting item for new item");
+            bulks
+                .insert(
+                    new_item,
+                    Bulk {
+                        weight: item_type::get_weight_for_item(&request.item_type),
+                        volume: item_type::get_volume_for_item(&request.item_type),
+                    },
+                )
+                .expect("failed inserting bulk for new item");
+            if item_type::item_is_stackable(&request.item_type) {
+                stackables
+                    .insert(new_item, Stackable { quantity: 1 })
+                    .expect("failed inserting stackable for new item");
+            }
             if item_type::item_is_consumable(&request.item_type) {
                 consumables
                     .insert(new_item, Consumable {})
@@ -39,7 +39,7 @@ impl<'a> System<'a> for LightItemSystem {
                         _ => "unknown".to_string(),
                     };
                     if entity == *player_entity {
-                        game_log.add(format!("you light the {}", item_name));
+                        game_log.add_system(format!("you light the {}", item_name));
                     }
                 }
                 
@@ -48,14 +48,14 @@ impl<'a> System<'a> for LightSystem {
                         off_hand_light_range as i32,
                     )
                 };
-                (light_radius as i32, position.level as u8, position.idx as i32)
+                (light_radius as i32, position.level, position.idx as i32)
             })
             .chain(
                 (&position, &causes_light)
                     .join()
                     .filter(|(_, causes_light)| causes_light.lit)
                     .map(|(position, causes_light)| {
-                        (causes_light.radius as i32, position.level as u8, position.idx as i32)
+                        (causes_light.radius as i32, position.level, position.idx as i32)
                     }),
             )
             .for_each(|(radius, level, pos_idx)| {
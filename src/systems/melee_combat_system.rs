@@ -1,8 +1,9 @@
+use crate::audio::SoundEffect;
 use crate::components::{
     causes_damage::DamageType, CausesDamage, CombatStats, DamageHistory, Equipment, Name, Position,
     SufferDamage, Viewshed, WantsToMelee,
 };
-use crate::services::{GameLog, ParticleEffectSpawner};
+use crate::services::{GameLog, ParticleEffectSpawner, SoundEffectQueue};
 use rltk::RandomNumberGenerator;
 use specs::{
     storage::GenericWriteStorage, Entities, Entity, Join, ReadExpect, ReadStorage, System,
@@ -33,6 +34,7 @@ impl<'a> System<'a> for MeleeCombatSystem {
         ReadStorage<'a, CausesDamage>,
         WriteExpect<'a, GameLog>,
         WriteExpect<'a, ParticleEffectSpawner>,
+        WriteExpect<'a, SoundEffectQueue>,
         ReadStorage<'a, Position>,
         WriteStorage<'a, DamageHistory>,
         ReadStorage<'a, Viewshed>,
@@ -51,6 +53,7 @@ impl<'a> System<'a> for MeleeCombatSystem {
             causes_damage,
             mut log,
             mut particle_effect_spawner,
+            mut sound_effects,
             positions,
             mut damage_histories,
             viewsheds,
@@ -94,7 +97,7 @@ impl<'a> System<'a> for MeleeCombatSystem {
                         None => "fist",
                     };
                     if dominant_hand_damage_dealt == 0 && in_player_sight {
-                        log.add(format_no_damage_text(
+                        log.add_combat(format_no_damage_text(
                             &name.name,
                             &target_name.name,
                             dominant_weapon_name,
@@ -110,7 +113,7 @@ impl<'a> System<'a> for MeleeCombatSystem {
                             history.events.insert(dominant_weapon_damage_type);
                         }
                         if in_player_sight {
-                            log.add(format_damage_text(
+                            log.add_combat(format_damage_text(
                                 &name.name,
                                 &target_name.name,
                                 dominant_weapon_name,
@@ -131,7 +134,7 @@ impl<'a> System<'a> for MeleeCombatSystem {
                         );
                         let off_weapon_name = &names.get(equipment.off_hand.unwrap()).unwrap().name;
                         if off_hand_damage_dealt == 0 && in_player_sight {
-                            log.add(format_no_damage_text(
+                            log.add_combat(format_no_damage_text(
                                 &name.name,
                                 &target_name.name,
                                 off_weapon_name,
@@ -148,7 +151,7 @@ impl<'a> System<'a> for MeleeCombatSystem {
                                 history.events.insert(off_weapon_damage_type);
                             }
                             if in_player_sight {
-                                log.add(format_damage_text(
+                                log.add_combat(format_damage_text(
                                     &name.name,
                                     &target_name.name,
                                     off_weapon_name,
@@ -158,6 +161,7 @@ impl<'a> System<'a> for MeleeCombatSystem {
                         }
                     }
                     if total_damage > 0 {
+                        sound_effects.request(SoundEffect::Hit, position.idx, position.level);
                         if let Some(damage_to_suffer) =
                             suffer_damage.get_mut_or_default(wants_to_melee.target)
                         {
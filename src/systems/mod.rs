@@ -1,10 +1,12 @@
 pub mod blood_spawn_system;
 pub mod close_door_system;
+pub mod cook_system;
 pub mod corpse_spawn_system;
 pub mod damage_system;
 pub mod debris_spawn_system;
 pub mod disarm_trap_system;
 pub mod douse_item_system;
+pub mod eat_system;
 pub mod equip_system;
 pub mod fire_burn_system;
 pub mod fire_die_system;
@@ -13,6 +15,8 @@ pub mod go_down_stairs_system;
 pub mod go_up_stairs_system;
 pub mod grab_system;
 pub mod hide_system;
+pub mod hunger_system;
+pub mod identify_system;
 pub mod item_collection_system;
 pub mod item_drop_system;
 pub mod item_spawn_system;
@@ -25,12 +29,16 @@ pub mod monster_ai_system;
 pub mod move_system;
 pub mod open_door_system;
 pub mod particle_spawn_system;
+pub mod regeneration_system;
 pub mod release_system;
 pub mod remove_particle_effects_system;
 pub mod remove_triggered_traps_system;
+pub mod reveal_secret_doors_system;
 pub mod reveal_traps_system;
+pub mod rot_system;
 pub mod search_for_hidden_system;
 pub mod set_trap_system;
+pub mod sound_effect_system;
 pub mod trap_spawn_system;
 pub mod trigger_system;
 pub mod update_memories_system;
@@ -39,11 +47,13 @@ pub mod use_item_system;
 pub mod visibility_system;
 pub use blood_spawn_system::BloodSpawnSystem;
 pub use close_door_system::CloseDoorSystem;
+pub use cook_system::CookSystem;
 pub use corpse_spawn_system::CorpseSpawnSystem;
 pub use damage_system::DamageSystem;
 pub use debris_spawn_system::DebrisSpawnSystem;
 pub use disarm_trap_system::DisarmTrapSystem;
 pub use douse_item_system::DouseItemSystem;
+pub use eat_system::EatSystem;
 pub use equip_system::EquipSystem;
 pub use fire_burn_system::FireBurnSystem;
 pub use fire_die_system::FireDieSystem;
@@ -52,6 +62,8 @@ pub use go_down_stairs_system::GoDownStairsSystem;
 pub use go_up_stairs_system::GoUpStairsSystem;
 pub use grab_system::GrabSystem;
 pub use hide_system::HideSystem;
+pub use hunger_system::HungerSystem;
+pub use identify_system::IdentifySystem;
 pub use item_collection_system::ItemCollectionSystem;
 pub use item_drop_system::ItemDropSystem;
 pub use item_spawn_system::ItemSpawnSystem;
@@ -64,12 +76,16 @@ pub use monster_ai_system::MonsterAI;
 pub use move_system::MoveSystem;
 pub use open_door_system::OpenDoorSystem;
 pub use particle_spawn_system::ParticleSpawnSystem;
+pub use regeneration_system::RegenerationSystem;
 pub use release_system::ReleaseSystem;
 pub use remove_particle_effects_system::RemoveParticleEffectsSystem;
 pub use remove_triggered_traps_system::RemoveTriggeredTrapsSystem;
+pub use reveal_secret_doors_system::RevealSecretDoorsSystem;
 pub use reveal_traps_system::RevealTrapsSystem;
+pub use rot_system::RotSystem;
 pub use search_for_hidden_system::SearchForHiddenSystem;
 pub use set_trap_system::SetTrapSystem;
+pub use sound_effect_system::SoundEffectSystem;
 pub use trap_spawn_system::TrapSpawnSystem;
 pub use trigger_system::TriggerSystem;
 pub use update_memories_system::UpdateMemoriesSystem;
@@ -157,7 +157,7 @@ impl<'a> System<'a> for MonsterAI {
                 for (_e, MemoryLocation(enemy_level, enemy_idx)) in
                     memory.last_known_enemy_positions.iter()
                 {
-                    if *enemy_level != position.level as i32 {
+                    if *enemy_level != position.level {
                         continue;
                     }
                     if let Some(action) = get_move_action_from_path(
@@ -203,7 +203,7 @@ impl<'a> System<'a> for MonsterAI {
                     None => level_utils::get_random_unblocked_floor_point(&level, &mut rng),
                 };
                 if let Some(idx) = destination_idx {
-                    memory.wander_destination = Some(MemoryLocation(position.level as i32, idx));
+                    memory.wander_destination = Some(MemoryLocation(position.level, idx));
                     if let Some(action) =
                         get_move_action_from_path(&level, current_idx, idx, &furniture, &doors)
                     {
@@ -1,5 +1,7 @@
+use crate::audio::SoundEffect;
 use crate::components::{door::DoorState, Door, Position, Renderable, Viewshed, WantsToOpenDoor};
 use crate::dungeon::{dungeon::Dungeon, level_utils};
+use crate::services::SoundEffectQueue;
 use rltk::{BLACK, DARK_GRAY, RGB};
 use specs::{Join, ReadStorage, System, WriteExpect, WriteStorage};
 use std::collections::HashSet;
@@ -14,6 +16,7 @@ impl<'a> System<'a> for OpenDoorSystem {
         WriteStorage<'a, Viewshed>,
         WriteStorage<'a, Door>,
         WriteStorage<'a, Renderable>,
+        WriteExpect<'a, SoundEffectQueue>,
     );
 
     fn run(&mut self, data: Self::SystemData) {
@@ -24,17 +27,23 @@ impl<'a> System<'a> for OpenDoorSystem {
             mut viewsheds,
             mut doors,
             mut renderables,
+            mut sound_effects,
         ) = data;
         let mut levels_with_door_open = HashSet::new();
         for intent in (&wants_to_open_door).join() {
             if let Some(door) = doors.get_mut(intent.door) {
                 door.state = DoorState::Opened;
                 let door_position = positions.get(intent.door).unwrap();
-                let mut level = dungeon.get_level_mut(door_position.level as u8).unwrap();
+                let mut level = dungeon.get_level_mut(door_position.level).unwrap();
                 level_utils::set_tile_to_floor(&mut level, door_position.idx);
                 level.blocked[door_position.idx] = false;
                 level.opaque[door_position.idx] = false;
                 levels_with_door_open.insert(door_position.level);
+                sound_effects.request(
+                    SoundEffect::DoorOpen,
+                    door_position.idx,
+                    door_position.level,
+                );
                 let mut door_renderable = renderables.get_mut(intent.door).unwrap();
                 door_renderable.bg = RGB::named(BLACK);
                 door_renderable.fg = RGB::named(DARK_GRAY);
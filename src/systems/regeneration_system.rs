@@ -0,0 +1,44 @@
+use crate::components::{
+    hunger::HungerState, regeneration::REGENERATION_THRESHOLD, CombatStats, Hunger, OnFire,
+    Regeneration, SufferDamage,
+};
+use specs::{Join, ReadStorage, System, WriteStorage};
+
+pub struct RegenerationSystem {}
+
+impl<'a> System<'a> for RegenerationSystem {
+    type SystemData = (
+        WriteStorage<'a, Regeneration>,
+        WriteStorage<'a, CombatStats>,
+        ReadStorage<'a, OnFire>,
+        ReadStorage<'a, SufferDamage>,
+        ReadStorage<'a, Hunger>,
+    );
+
+    fn run(&mut self, data: Self::SystemData) {
+        let (mut regenerations, mut combat_stats, on_fires, suffer_damage, hungers) = data;
+        // nothing heals on a turn it is burning or being hurt, or while it is starving
+        for (regeneration, stats, _, _, hunger) in (
+            &mut regenerations,
+            &mut combat_stats,
+            !&on_fires,
+            !&suffer_damage,
+            (&hungers).maybe(),
+        )
+            .join()
+        {
+            if hunger.map_or(false, |hunger| hunger.get_state() == HungerState::Starving) {
+                continue;
+            }
+            if stats.hp >= stats.max_hp {
+                regeneration.progress = 0;
+                continue;
+            }
+            regeneration.progress += regeneration.rate;
+            if regeneration.progress >= REGENERATION_THRESHOLD {
+                regeneration.progress -= REGENERATION_THRESHOLD;
+                stats.hp = (stats.hp + 1).min(stats.max_hp);
+            }
+        }
+    }
+}
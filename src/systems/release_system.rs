@@ -74,7 +74,7 @@ impl<'a> System<'a> for ReleaseSystem {
                     },
                     None => "nothing".to_string(),
                 };
-                log.add(format!("You release {}", name));
+                log.add_system(format!("You release {}", name));
             }
             grabbings.remove(*entity);
         });
@@ -0,0 +1,33 @@
+use crate::components::{Door, Hidden, Position};
+use crate::dungeon::{dungeon::Dungeon, level_utils, tile_type::TileType};
+use specs::{Entity, Join, ReadExpect, ReadStorage, System, WriteExpect};
+
+pub struct RevealSecretDoorsSystem {}
+
+// Secret doors sit on wall tiles until the player finds them, at which point the tile becomes a
+// regular door so that it can be rendered, pathed through and opened.
+impl<'a> System<'a> for RevealSecretDoorsSystem {
+    type SystemData = (
+        WriteExpect<'a, Dungeon>,
+        ReadExpect<'a, Entity>,
+        ReadStorage<'a, Door>,
+        ReadStorage<'a, Hidden>,
+        ReadStorage<'a, Position>,
+    );
+
+    fn run(&mut self, data: Self::SystemData) {
+        let (mut dungeon, player_entity, doors, hiddens, positions) = data;
+        for (_door, hidden, position) in (&doors, &hiddens, &positions).join() {
+            if !hidden.found_by.contains(&*player_entity) {
+                continue;
+            }
+            let level = dungeon.get_level_mut(position.level).unwrap();
+            if level.tiles[position.idx] == TileType::Wall {
+                level_utils::set_tile_to_door(level, position.idx);
+                level.revealed_tiles[position.idx] = true;
+                level.blocked[position.idx] = true;
+                level.opaque[position.idx] = true;
+            }
+        }
+    }
+}
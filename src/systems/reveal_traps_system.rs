@@ -40,7 +40,7 @@ impl<'a> System<'a> for RevealTrapsSystem<'a> {
                         let inserted = this_hidden.found_by.insert(*player_ent);
                         if inserted {
                             if let Some(name) = names.get(e) {
-                                log.add(format!("You spotted a {}.", name.name));
+                                log.add_discovery(format!("You spotted a {}.", name.name));
                             }
                         }
                     }
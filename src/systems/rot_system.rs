@@ -0,0 +1,21 @@
+use crate::components::{Name, Rotting};
+use specs::{Join, System, WriteStorage};
+
+pub struct RotSystem {}
+
+impl<'a> System<'a> for RotSystem {
+    type SystemData = (WriteStorage<'a, Rotting>, WriteStorage<'a, Name>);
+
+    fn run(&mut self, data: Self::SystemData) {
+        let (mut rotting, mut names) = data;
+        for (rotting, name) in (&mut rotting, (&mut names).maybe()).join() {
+            let was_rotten = rotting.is_rotten();
+            rotting.age += 1;
+            if !was_rotten && rotting.is_rotten() {
+                if let Some(name) = name {
+                    name.name = format!("rotten {}", name.name);
+                }
+            }
+        }
+    }
+}
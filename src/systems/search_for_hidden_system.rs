@@ -63,12 +63,12 @@ impl<'a> System<'a> for SearchForHiddenSystem {
                                     let inserted = hidden.found_by.insert(entity);
                                     if is_player && inserted {
                                         let hidden_name = names.get(*hidden_entity).unwrap();
-                                        log.add(format!("You spotted a {}", hidden_name.name));
+                                        log.add_discovery(format!("You spotted a {}", hidden_name.name));
                                     }
                                 }
                                 _ => {
                                     if is_player {
-                                        log.add("You found nothing.".to_string())
+                                        log.add_discovery("You found nothing.".to_string())
                                     }
                                 }
                             }
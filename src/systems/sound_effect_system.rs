@@ -0,0 +1,42 @@
+use crate::audio::{AudioBackend, SoundEffect};
+use crate::components::Position;
+use crate::dungeon::{dungeon::Dungeon, level_utils};
+use crate::services::SoundEffectQueue;
+use specs::{Entity, ReadExpect, ReadStorage, System, WriteExpect};
+use std::collections::HashSet;
+
+const HEARING_DISTANCE: f32 = 20.0;
+
+pub struct SoundEffectSystem<'a> {
+    pub audio: &'a mut dyn AudioBackend,
+}
+
+impl<'a> System<'a> for SoundEffectSystem<'a> {
+    type SystemData = (
+        ReadExpect<'a, Dungeon>,
+        ReadExpect<'a, Entity>,
+        ReadStorage<'a, Position>,
+        WriteExpect<'a, SoundEffectQueue>,
+    );
+
+    fn run(&mut self, data: Self::SystemData) {
+        let (dungeon, player_ent, positions, mut queue) = data;
+        let player_position = positions.get(*player_ent).unwrap();
+        let level = dungeon.get_level(player_position.level).unwrap();
+        // several of the same sound in one turn play once rather than stacking up
+        let effects: HashSet<SoundEffect> = queue
+            .requests
+            .iter()
+            .filter(|request| request.level == player_position.level)
+            .filter(|request| {
+                level_utils::get_distance_between_idxs(level, player_position.idx, request.idx)
+                    <= HEARING_DISTANCE
+            })
+            .map(|request| request.effect)
+            .collect();
+        effects
+            .into_iter()
+            .for_each(|effect| self.audio.play_sound_effect(effect));
+        queue.requests.clear();
+    }
+}
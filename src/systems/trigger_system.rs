@@ -1,6 +1,7 @@
+use crate::audio::SoundEffect;
 use crate::components::{CausesDamage, DamageHistory, EntityMoved, EntryTrigger, Hidden, Name, Position, SufferDamage, Triggered, Viewshed};
 use crate::dungeon::{dungeon::Dungeon, level_utils};
-use crate::services::{GameLog, ParticleEffectSpawner};
+use crate::services::{GameLog, ParticleEffectSpawner, SoundEffectQueue};
 use rltk::RandomNumberGenerator;
 use specs::{
     storage::GenericWriteStorage, Entities, Entity, Join, ReadExpect, ReadStorage, System,
@@ -24,6 +25,7 @@ impl<'a> System<'a> for TriggerSystem {
         WriteStorage<'a, DamageHistory>,
         ReadStorage<'a, Viewshed>,
         WriteExpect<'a, ParticleEffectSpawner>,
+        WriteExpect<'a, SoundEffectQueue>,
         WriteExpect<'a, GameLog>,
         Entities<'a>,
         WriteExpect<'a, RandomNumberGenerator>,
@@ -44,6 +46,7 @@ impl<'a> System<'a> for TriggerSystem {
             mut damage_histories,
             viewsheds,
             mut particle_spawner,
+            mut sound_effects,
             mut log,
             ents,
             mut rng,
@@ -60,7 +63,7 @@ impl<'a> System<'a> for TriggerSystem {
                     if player_viewshed.visible_tiles.contains(&pos.idx) {
                         if let Some(triggered_name) = names.get(*maybe_triggered) {
                             if let Some(ent_name) = names.get(entity) {
-                                log.add(format!(
+                                log.add_combat(format!(
                                     "{} triggers {}",
                                     &ent_name.name, &triggered_name.name
                                 ));
@@ -80,6 +83,7 @@ impl<'a> System<'a> for TriggerSystem {
                             damage_history.events.insert(*damage_type);
                         }
                     }
+                    sound_effects.request(SoundEffect::TrapSnap, pos.idx, pos.level);
                     hidden.remove(*maybe_triggered);
                     triggered
                         .insert(*maybe_triggered, Triggered {})
@@ -22,7 +22,7 @@ impl<'a> System<'a> for UpdateMemoriesSystem {
             {
                 memory.last_known_enemy_positions.insert(
                     *player_entity,
-                    MemoryLocation(player_position.level as i32, player_position.idx),
+                    MemoryLocation(player_position.level, player_position.idx),
                 );
                 if let Some(hide_intent) = hide_intents.get(*player_entity) {
                     if let Some(container_ent) = hide_intent.hiding_spot {
@@ -38,7 +38,7 @@ impl<'a> System<'a> for UpdateMemoriesSystem {
             let reached_wander_destination = match memory.wander_destination {
                 None => false,
                 Some(MemoryLocation(level, idx)) => {
-                    idx == position.idx && level == position.level as i32
+                    idx == position.idx && level == position.level
                 }
             };
             if reached_wander_destination {
@@ -47,7 +47,7 @@ impl<'a> System<'a> for UpdateMemoriesSystem {
             let found_mem_pos = {
                 match memory.last_known_enemy_positions.iter().find(
                     |(_e, MemoryLocation(level, idx))| {
-                        *idx == position.idx && *level == position.level as i32
+                        *idx == position.idx && *level == position.level
                     },
                 ) {
                     Some((e, _location)) => Some(*e),
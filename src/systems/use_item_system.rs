@@ -1,10 +1,12 @@
+use crate::audio::SoundEffect;
 use crate::components::{
     AreaOfEffect, CausesDamage, CausesFire, CausesLight, CombatStats, Consumable, DamageHistory,
-    Flammable, Inventory, Name, OnFire, Paralyze, Paralyzed, Position, ProvidesHealing,
+    Flammable, Inventory, Name, OnFire, Paralyze, Paralyzed, Position, ProvidesHealing, Stackable,
     SufferDamage, WantsToUse,
 };
 use crate::dungeon::{dungeon::Dungeon, level_utils};
-use crate::services::{GameLog, ParticleEffectSpawner};
+use crate::inventory;
+use crate::services::{GameLog, ParticleEffectSpawner, SoundEffectQueue};
 use rltk::{RandomNumberGenerator, BLACK, MAGENTA, ORANGE, RED, RGB};
 use specs::{
     storage::GenericWriteStorage, Entities, Entity, Join, ReadExpect, ReadStorage, System,
@@ -30,6 +32,7 @@ impl<'a> System<'a> for UseItemSystem {
         ReadStorage<'a, Paralyze>,
         WriteStorage<'a, Paralyzed>,
         WriteExpect<'a, ParticleEffectSpawner>,
+        WriteExpect<'a, SoundEffectQueue>,
         ReadStorage<'a, Position>,
         ReadStorage<'a, CausesFire>,
         ReadStorage<'a, Flammable>,
@@ -38,6 +41,7 @@ impl<'a> System<'a> for UseItemSystem {
         WriteStorage<'a, DamageHistory>,
         WriteStorage<'a, Inventory>,
         WriteExpect<'a, RandomNumberGenerator>,
+        WriteStorage<'a, Stackable>,
     );
     fn run(&mut self, data: Self::SystemData) {
         let (
@@ -56,6 +60,7 @@ impl<'a> System<'a> for UseItemSystem {
             causes_paralysis,
             mut is_paralyzed,
             mut particle_spawner,
+            mut sound_effects,
             positions,
             causes_fire,
             flammables,
@@ -64,6 +69,7 @@ impl<'a> System<'a> for UseItemSystem {
             mut damage_histories,
             mut inventories,
             mut rng,
+            mut stackables,
         ) = data;
         let player_position = positions.get(*player_entity).unwrap();
         let level = dungeon.get_level(player_position.level).unwrap();
@@ -117,6 +123,7 @@ impl<'a> System<'a> for UseItemSystem {
                         on_fire
                             .insert(target, OnFire {})
                             .expect("couldn't light target on fire");
+                        sound_effects.request(SoundEffect::Fire, pos.idx, pos.level);
                         causes_light
                             .insert(
                                 target,
@@ -151,7 +158,7 @@ impl<'a> System<'a> for UseItemSystem {
                         if ent_is_player {
                             if let Some(mob_name) = names.get(target) {
                                 let item_name = names.get(to_use.item).unwrap();
-                                game_log.add(format!(
+                                game_log.add_combat(format!(
                                     "you use {} on {} causing {} damage",
                                     item_name.name, mob_name.name, damage
                                 ));
@@ -169,7 +176,7 @@ impl<'a> System<'a> for UseItemSystem {
                             pos.level,
                         );
                         if ent_is_player {
-                            game_log.add(format!(
+                            game_log.add_combat(format!(
                                 "You use the {}, healing {} hp.",
                                 names.get(to_use.item).unwrap().name,
                                 heals.amount
@@ -197,7 +204,7 @@ impl<'a> System<'a> for UseItemSystem {
                         if ent_is_player {
                             let mob_name = names.get(target).unwrap();
                             let item_name = names.get(to_use.item).unwrap();
-                            game_log.add(format!(
+                            game_log.add_combat(format!(
                                 "you use {} on {}, confusing them.",
                                 item_name.name, mob_name.name,
                             ));
@@ -205,7 +212,7 @@ impl<'a> System<'a> for UseItemSystem {
                         if target == *player_entity {
                             let mob_name = names.get(entity).unwrap();
                             let item_name = names.get(to_use.item).unwrap();
-                            game_log.add(format!(
+                            game_log.add_combat(format!(
                                 "{} uses {} on you, you are confused.",
                                 item_name.name, mob_name.name,
                             ));
@@ -214,6 +221,9 @@ impl<'a> System<'a> for UseItemSystem {
                 }
             }
             if let Some(_) = consumables.get(to_use.item) {
+                if inventory::take_one_from_stack(&mut stackables, to_use.item) {
+                    continue;
+                }
                 inventory.items.remove(&to_use.item);
                 entities.delete(to_use.item).expect("Delete Failed");
             };
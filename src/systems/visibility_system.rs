@@ -1,6 +1,10 @@
-use crate::dungeon::{dungeon::Dungeon, level_utils};
+use crate::dungeon::{dungeon::Dungeon, level_utils, remembered_entity::RememberedEntity};
 use crate::{
-    components::{Monster, Position, Viewshed},
+    auto_explore::ExploreInterrupts,
+    components::{
+        door::DoorState, Container, Door, Hidden, Hiding, Item, Monster, Name, ParticleLifetime,
+        Position, Renderable, Viewshed,
+    },
     interaction_type::InteractionType,
 };
 use specs::{Entities, Entity, Join, ReadExpect, ReadStorage, System, WriteExpect, WriteStorage};
@@ -9,6 +13,8 @@ use std::iter::FromIterator;
 
 pub struct VisibilitySystem<'a> {
     pub queued_action: &'a mut Option<InteractionType>,
+    pub explore_interrupts: ExploreInterrupts,
+    pub monsters_in_view: &'a mut bool,
 }
 
 impl<'a> System<'a> for VisibilitySystem<'a> {
@@ -19,9 +25,32 @@ impl<'a> System<'a> for VisibilitySystem<'a> {
         ReadStorage<'a, Position>,
         ReadExpect<'a, Entity>,
         ReadStorage<'a, Monster>,
+        ReadStorage<'a, Item>,
+        ReadStorage<'a, Hidden>,
+        ReadStorage<'a, Hiding>,
+        ReadStorage<'a, Renderable>,
+        ReadStorage<'a, Name>,
+        ReadStorage<'a, Door>,
+        ReadStorage<'a, ParticleLifetime>,
+        ReadStorage<'a, Container>,
     );
     fn run(&mut self, data: Self::SystemData) {
-        let (mut dungeon, entities, mut viewsheds, positions, player_ent, monsters) = data;
+        let (
+            mut dungeon,
+            entities,
+            mut viewsheds,
+            positions,
+            player_ent,
+            monsters,
+            items,
+            hidden,
+            hiding,
+            renderables,
+            names,
+            doors,
+            particles,
+            containers,
+        ) = data;
         let player_position = &positions.get(*player_ent).unwrap();
         let enemies_in_player_sight_at_start: Box<[Entity]> = {
             let player_viewshed = &viewsheds.get(*player_ent).unwrap();
@@ -34,6 +63,18 @@ impl<'a> System<'a> for VisibilitySystem<'a> {
                 .map(|(_p, e, _m)| e)
                 .collect()
         };
+        let items_in_player_sight_at_start: Box<[Entity]> = {
+            let player_viewshed = &viewsheds.get(*player_ent).unwrap();
+            (&positions, &entities, &items, (&hidden).maybe())
+                .join()
+                .filter(|(p, _e, _i, h)| {
+                    p.level == player_position.level
+                        && player_viewshed.visible_tiles.contains(&p.idx)
+                        && h.map_or(true, |h| h.found_by.contains(&*player_ent))
+                })
+                .map(|(_p, e, _i, _h)| e)
+                .collect()
+        };
         {
             for (ent, viewshed, position) in (&entities, &mut viewsheds, &positions).join() {
                 let level = dungeon.get_level_mut(position.level).unwrap();
@@ -76,6 +117,61 @@ impl<'a> System<'a> for VisibilitySystem<'a> {
                 }
             }
         }
+        {
+            let player_viewshed = &viewsheds.get(*player_ent).unwrap();
+            let level = dungeon.get_level_mut(player_position.level).unwrap();
+            let seen: Vec<RememberedEntity> = (
+                &entities,
+                &positions,
+                &renderables,
+                (&names).maybe(),
+                (&hidden).maybe(),
+                (&hiding).maybe(),
+                (&doors).maybe(),
+                (&items).maybe(),
+                (&containers).maybe(),
+                !&particles,
+            )
+                .join()
+                .filter(|(e, p, _r, _n, h, hiding, _d, _i, _c, _)| {
+                    *e != *player_ent
+                        && p.level == player_position.level
+                        && player_viewshed.visible_tiles.contains(&p.idx)
+                        && h.map_or(true, |h| h.found_by.contains(&*player_ent))
+                        && hiding.is_none()
+                })
+                .map(|(e, p, r, name, _h, _hiding, door, item, container, _)| {
+                    let name = name.map_or("something".to_string(), |n| n.name.clone());
+                    RememberedEntity {
+                        idx: p.idx,
+                        name: match door.map(|d| &d.state) {
+                            Some(DoorState::Opened) => format!("{} (open)", name),
+                            Some(DoorState::Closed) => format!("{} (closed)", name),
+                            None => name,
+                        },
+                        glyph: r.glyph,
+                        fg: r.fg,
+                        layer: r.layer,
+                        is_landmark: item.is_some() || container.is_some(),
+                        entity: Some(e),
+                    }
+                })
+                .collect();
+            level.remembered_entities.retain(|remembered| {
+                !player_viewshed.visible_tiles.contains(&remembered.idx)
+                    && remembered
+                        .entity
+                        .map_or(true, |e| !seen.iter().any(|s| s.entity == Some(e)))
+            });
+            level.remembered_entities.extend(seen);
+        }
+        *self.monsters_in_view = {
+            let player_viewshed = &viewsheds.get(*player_ent).unwrap();
+            (&positions, &monsters, !&hiding).join().any(|(p, _m, _)| {
+                p.level == player_position.level && player_viewshed.visible_tiles.contains(&p.idx)
+            })
+        };
+        let exploring = *self.queued_action == Some(InteractionType::Explore);
         if let Some(_) = {
             let player_viewshed = &viewsheds.get(*player_ent).unwrap();
             (&positions, &entities, &monsters)
@@ -86,7 +182,23 @@ impl<'a> System<'a> for VisibilitySystem<'a> {
                         && !enemies_in_player_sight_at_start.contains(e)
                 })
         } {
-            self.queued_action.take();
+            if !exploring || self.explore_interrupts.on_monster {
+                self.queued_action.take();
+            }
+        }
+        if exploring && self.explore_interrupts.on_item {
+            let player_viewshed = &viewsheds.get(*player_ent).unwrap();
+            let spotted_new_item = (&positions, &entities, &items, (&hidden).maybe())
+                .join()
+                .any(|(p, e, _i, h)| {
+                    p.level == player_position.level
+                        && player_viewshed.visible_tiles.contains(&p.idx)
+                        && h.map_or(true, |h| h.found_by.contains(&*player_ent))
+                        && !items_in_player_sight_at_start.contains(&e)
+                });
+            if spotted_new_item {
+                self.queued_action.take();
+            }
         }
     }
 }
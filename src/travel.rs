@@ -0,0 +1,76 @@
+use crate::components::Position;
+use crate::dungeon::{dungeon::Dungeon, level::Level};
+use specs::{Entity, World, WorldExt};
+
+pub struct Landmark {
+    pub name: String,
+    pub idx: usize,
+}
+
+fn get_player_level<'a>(world: &World, dungeon: &'a Dungeon) -> &'a Level {
+    let player_ent = world.fetch::<Entity>();
+    let positions = world.read_storage::<Position>();
+    let player_position = positions.get(*player_ent).unwrap();
+    dungeon.get_level(player_position.level).unwrap()
+}
+
+fn get_known_idx(level: &Level, idx: Option<usize>) -> Option<usize> {
+    idx.filter(|idx| level.revealed_tiles[*idx])
+}
+
+pub fn get_known_down_stairs(world: &World) -> Option<usize> {
+    let dungeon = world.fetch::<Dungeon>();
+    let level = get_player_level(world, &dungeon);
+    get_known_idx(level, level.stairs_down)
+}
+
+pub fn get_known_up_stairs(world: &World) -> Option<usize> {
+    let dungeon = world.fetch::<Dungeon>();
+    let level = get_player_level(world, &dungeon);
+    get_known_idx(level, level.stairs_up)
+}
+
+pub fn get_known_exit(world: &World) -> Option<usize> {
+    let dungeon = world.fetch::<Dungeon>();
+    let level = get_player_level(world, &dungeon);
+    get_known_idx(level, level.exit)
+}
+
+pub fn get_known_landmarks(world: &World) -> Vec<Landmark> {
+    let dungeon = world.fetch::<Dungeon>();
+    let level = get_player_level(world, &dungeon);
+    let mut landmarks = vec![];
+    let features = [
+        ("Down Stairs", get_known_idx(level, level.stairs_down)),
+        ("Up Stairs", get_known_idx(level, level.stairs_up)),
+        ("Dungeon Exit", get_known_idx(level, level.exit)),
+    ];
+    for (name, idx) in features.iter() {
+        if let Some(idx) = idx {
+            landmarks.push(Landmark {
+                name: name.to_string(),
+                idx: *idx,
+            });
+        }
+    }
+    for (idx, branch) in level.branch_stairs.iter() {
+        if level.revealed_tiles[*idx] {
+            landmarks.push(Landmark {
+                name: format!("Stairs to the {}", branch.get_name()),
+                idx: *idx,
+            });
+        }
+    }
+    // only what the player remembers seeing, things may have moved or gone since
+    landmarks.extend(
+        level
+            .remembered_entities
+            .iter()
+            .filter(|remembered| remembered.is_landmark)
+            .map(|remembered| Landmark {
+                name: remembered.name.clone(),
+                idx: remembered.idx,
+            }),
+    );
+    landmarks
+}
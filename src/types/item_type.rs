@@ -18,7 +18,7 @@ pub fn get_glyph_for_item(item_type: &ItemType) -> u16 {
 pub fn get_name_for_item(item_type: &ItemType) -> String {
     match item_type {
         ItemType::Caltrops => "Caltrops".to_string(),
-        ItemType::BearTrap => "Beartrap".to_string(),
+        ItemType::BearTrap => "Bear Trap".to_string(),
     }
 }
 
@@ -34,6 +34,26 @@ pub fn item_is_consumable(item_type: &ItemType) -> bool {
     }
 }
 
+pub fn item_is_stackable(item_type: &ItemType) -> bool {
+    match item_type {
+        ItemType::Caltrops | ItemType::BearTrap => true,
+    }
+}
+
+pub fn get_weight_for_item(item_type: &ItemType) -> u32 {
+    match item_type {
+        ItemType::Caltrops => 3,
+        ItemType::BearTrap => 10,
+    }
+}
+
+pub fn get_volume_for_item(item_type: &ItemType) -> u32 {
+    match item_type {
+        ItemType::Caltrops => 2,
+        ItemType::BearTrap => 4,
+    }
+}
+
 pub fn get_range_for_item(item_type: &ItemType) -> Option<u32> {
     match item_type {
         ItemType::Caltrops => Some(2),
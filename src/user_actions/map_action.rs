@@ -32,12 +32,14 @@ pub enum MapAction {
     MoveDownRight,
     SearchContainer,
     StayStill,
+    Rest,
     OpenDoor,
     PickupItem,
     ShowInventoryMenu,
     ShowDropMenu,
     ShowActionMenu,
     ShowEquipmentMenu,
+    ShowMessageLog,
     SearchHidden,
     DisarmTrap,
     ArmTrap,
@@ -48,13 +50,71 @@ pub enum MapAction {
     Exit,
     LeaveDungeon,
     Interact,
+    Examine,
+    AutoExplore,
+    TravelToDownStairs,
+    TravelToUpStairs,
+    TravelToExit,
+    ShowLandmarkMenu,
+    ShowOverview,
+    ToggleMinimap,
+    TravelToMouse,
+    InteractAtMouse,
     ScrollLeft,
     ScrollRight,
     ScrollDown,
     ScrollUp,
+    RepeatLastAction,
+    RecordMacro1,
+    RecordMacro2,
+    RecordMacro3,
+    PlayMacro1,
+    PlayMacro2,
+    PlayMacro3,
 }
 
 impl MapAction {
+    pub fn get_direction(&self) -> Option<(i32, i32)> {
+        match self {
+            Self::MoveLeft | Self::ActLeft | Self::AutoActLeft => Some((-1, 0)),
+            Self::MoveRight | Self::ActRight | Self::AutoActRight => Some((1, 0)),
+            Self::MoveUp | Self::ActUp | Self::AutoActUp => Some((0, -1)),
+            Self::MoveDown | Self::ActDown | Self::AutoActDown => Some((0, 1)),
+            Self::MoveUpLeft | Self::ActUpLeft | Self::AutoActUpLeft => Some((-1, -1)),
+            Self::MoveUpRight | Self::ActUpRight | Self::AutoActUpRight => Some((1, -1)),
+            Self::MoveDownLeft | Self::ActDownLeft | Self::AutoActDownLeft => Some((-1, 1)),
+            Self::MoveDownRight | Self::ActDownRight | Self::AutoActDownRight => Some((1, 1)),
+            _ => None,
+        }
+    }
+
+    // actions that take a turn without a menu or target, so they can be repeated or recorded
+    pub fn is_repeatable(&self) -> bool {
+        match self {
+            Self::MoveLeft
+            | Self::MoveRight
+            | Self::MoveUp
+            | Self::MoveDown
+            | Self::MoveUpLeft
+            | Self::MoveUpRight
+            | Self::MoveDownLeft
+            | Self::MoveDownRight
+            | Self::StayStill
+            | Self::SearchHidden
+            | Self::ReleaseFurniture => true,
+            _ => false,
+        }
+    }
+
+    pub fn get_macro_slot(&self) -> Option<usize> {
+        match self {
+            Self::RecordMacro1 | Self::PlayMacro1 => Some(0),
+            Self::RecordMacro2 | Self::PlayMacro2 => Some(1),
+            Self::RecordMacro3 | Self::PlayMacro3 => Some(2),
+            _ => None,
+        }
+    }
+
     pub fn actions() -> Box<[Self]> {
         Box::new([
             Self::ActLeft,
@@ -83,12 +143,14 @@ impl MapAction {
             Self::MoveDownRight,
             Self::SearchContainer,
             Self::StayStill,
+            Self::Rest,
             Self::OpenDoor,
             Self::PickupItem,
             Self::ShowInventoryMenu,
             Self::ShowDropMenu,
             Self::ShowActionMenu,
             Self::ShowEquipmentMenu,
+            Self::ShowMessageLog,
             Self::SearchHidden,
             Self::DisarmTrap,
             Self::ArmTrap,
@@ -99,10 +161,27 @@ impl MapAction {
             Self::Exit,
             Self::LeaveDungeon,
             Self::Interact,
+            Self::Examine,
+            Self::AutoExplore,
+            Self::TravelToDownStairs,
+            Self::TravelToUpStairs,
+            Self::TravelToExit,
+            Self::ShowLandmarkMenu,
+            Self::ShowOverview,
+            Self::ToggleMinimap,
+            Self::TravelToMouse,
+            Self::InteractAtMouse,
             Self::ScrollLeft,
             Self::ScrollRight,
             Self::ScrollDown,
             Self::ScrollUp,
+            Self::RepeatLastAction,
+            Self::RecordMacro1,
+            Self::RecordMacro2,
+            Self::RecordMacro3,
+            Self::PlayMacro1,
+            Self::PlayMacro2,
+            Self::PlayMacro3,
         ])
     }
 }
@@ -141,12 +220,14 @@ impl Display for MapAction {
                 Self::MoveDownRight => "Move Down Right",
                 Self::SearchContainer => "Search Container",
                 Self::StayStill => "Stay Still",
+                Self::Rest => "Rest Until Healed",
                 Self::OpenDoor => "Open Door",
                 Self::PickupItem => "Pickup Item",
                 Self::ShowInventoryMenu => "Show Inventory Menu",
                 Self::ShowDropMenu => "Show Drop Menu",
                 Self::ShowActionMenu => "Show Action Menu",
                 Self::ShowEquipmentMenu => "Show Equipment Menu",
+                Self::ShowMessageLog => "Show Message Log",
                 Self::SearchHidden => "Search Area",
                 Self::DisarmTrap => "Disarm Trap",
                 Self::ArmTrap => "Arm Trap",
@@ -157,10 +238,27 @@ impl Display for MapAction {
                 Self::Exit => "Exit",
                 Self::LeaveDungeon => "Leave Dungeon",
                 Self::Interact => "Interact",
+                Self::Examine => "Examine",
+                Self::AutoExplore => "Auto Explore",
+                Self::TravelToDownStairs => "Travel To Down Stairs",
+                Self::TravelToUpStairs => "Travel To Up Stairs",
+                Self::TravelToExit => "Travel To Exit",
+                Self::ShowLandmarkMenu => "Show Landmark Menu",
+                Self::ShowOverview => "Show Level Overview",
+                Self::ToggleMinimap => "Toggle Minimap",
+                Self::TravelToMouse => "Travel To Cursor",
+                Self::InteractAtMouse => "Interact At Cursor",
                 Self::ScrollLeft => "Scroll Left",
                 Self::ScrollRight => "Scroll Right",
                 Self::ScrollDown => "Scroll Down",
                 Self::ScrollUp => "Scroll Up",
+                Self::RepeatLastAction => "Repeat Last Action",
+                Self::RecordMacro1 => "Record Macro 1",
+                Self::RecordMacro2 => "Record Macro 2",
+                Self::RecordMacro3 => "Record Macro 3",
+                Self::PlayMacro1 => "Play Macro 1",
+                Self::PlayMacro2 => "Play Macro 2",
+                Self::PlayMacro3 => "Play Macro 3",
             })
         )
     }
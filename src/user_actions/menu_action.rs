@@ -14,6 +14,7 @@ pub enum MenuAction {
     PreviousMenu,
     NextPage,
     PreviousPage,
+    Reset,
 }
 
 impl Display for MenuAction {
@@ -32,6 +33,7 @@ impl Display for MenuAction {
                 MenuAction::PreviousMenu => "Previous Menu",
                 MenuAction::NextPage => "Next Page",
                 MenuAction::PreviousPage => "Previous Page",
+                MenuAction::Reset => "Reset To Defaults",
             })
         )
     }
@@ -6,6 +6,30 @@ use std::fmt::Display;
 pub enum TargetingAction {
     Exit,
     Selected,
+    MoveCursorLeft,
+    MoveCursorRight,
+    MoveCursorUp,
+    MoveCursorDown,
+    MoveCursorUpLeft,
+    MoveCursorUpRight,
+    MoveCursorDownLeft,
+    MoveCursorDownRight,
+}
+
+impl TargetingAction {
+  pub fn get_direction(&self) -> Option<(i32, i32)> {
+      match self {
+          TargetingAction::MoveCursorLeft => Some((-1, 0)),
+          TargetingAction::MoveCursorRight => Some((1, 0)),
+          TargetingAction::MoveCursorUp => Some((0, -1)),
+          TargetingAction::MoveCursorDown => Some((0, 1)),
+          TargetingAction::MoveCursorUpLeft => Some((-1, -1)),
+          TargetingAction::MoveCursorUpRight => Some((1, -1)),
+          TargetingAction::MoveCursorDownLeft => Some((-1, 1)),
+          TargetingAction::MoveCursorDownRight => Some((1, 1)),
+          _ => None,
+      }
+  }
 }
 
 impl Display for TargetingAction {
@@ -16,6 +40,14 @@ impl Display for TargetingAction {
           String::from(match self {
               TargetingAction::Exit => "Exit Screen",
               TargetingAction::Selected => "Select Target",
+              TargetingAction::MoveCursorLeft => "Move Cursor Left",
+              TargetingAction::MoveCursorRight => "Move Cursor Right",
+              TargetingAction::MoveCursorUp => "Move Cursor Up",
+              TargetingAction::MoveCursorDown => "Move Cursor Down",
+              TargetingAction::MoveCursorUpLeft => "Move Cursor Up Left",
+              TargetingAction::MoveCursorUpRight => "Move Cursor Up Right",
+              TargetingAction::MoveCursorDownLeft => "Move Cursor Down Left",
+              TargetingAction::MoveCursorDownRight => "Move Cursor Down Right",
           })
       )
   }
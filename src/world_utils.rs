@@ -5,15 +5,19 @@ use crate::{
         Dousable, EntityMoved, EntryTrigger, Equipable, Equipment, Flammable, Furniture, Grabbable,
         Grabbing, Hidden, Hiding, HidingSpot, Info, Inventory, Item, Lightable, Memory, Monster,
         Name, Objective, OnFire, ParticleLifetime, Player, Position, Potion, ProvidesHealing,
-        Ranged, Renderable, Saveable, SerializationHelper, SingleActivation, SufferDamage, Trap,
+        Ranged, Regeneration, Renderable, Saveable, SerializationHelper, SingleActivation,
+        SufferDamage, Trap, Cookable, CookingSpot, Edible, Hunger, Rotting, WantsToCook, WantsToEat,
+        Bulk, Capacity, Stackable, Identifiable, Identifies,
         Triggered, Viewshed, WantsToCloseDoor, WantsToDisarmTrap, WantsToDouse, WantsToDropItem,
         WantsToEquip, WantsToExit, WantsToGoDownStairs, WantsToGrab, WantsToHide, WantsToLight,
         WantsToMelee, WantsToMove, WantsToOpenDoor, WantsToPickUpItem, WantsToReleaseGrabbed,
         WantsToSearchHidden, WantsToTrap, WantsToUse,WantsToGoUpStairs
     },
+    copy,
+    screens::viewport::{Viewport, DEFAULT_VIEWPORT_MARGIN},
     services::{
-        BloodSpawner, CorpseSpawner, DebrisSpawner, GameLog, ItemSpawner, ParticleEffectSpawner,
-        TrapSpawner,
+        BloodSpawner, CorpseSpawner, DebrisSpawner, GameLog, Identification, ItemSpawner,
+        ParticleEffectSpawner, SoundEffectQueue, TrapSpawner,
     },
 };
 use rltk::RandomNumberGenerator;
@@ -72,12 +76,23 @@ pub fn initialize_new_game(world: &mut World) {
     world.write_storage::<Disarmable>().clear();
     world.write_storage::<DamageHistory>().clear();
     world.write_storage::<Inventory>().clear();
+    world.write_storage::<Regeneration>().clear();
+    world.write_storage::<Hunger>().clear();
+    world.write_storage::<Edible>().clear();
+    world.write_storage::<Cookable>().clear();
+    world.write_storage::<Rotting>().clear();
+    world.write_storage::<CookingSpot>().clear();
+    world.write_storage::<Bulk>().clear();
+    world.write_storage::<Capacity>().clear();
+    world.write_storage::<Stackable>().clear();
+    world.write_storage::<Identifiable>().clear();
+    world.write_storage::<Identifies>().clear();
     world.remove::<SimpleMarkerAllocator<Saveable>>();
     world.insert(SimpleMarkerAllocator::<Saveable>::new());
     world.remove::<GameLog>();
-    world.insert(GameLog {
-        entries: vec!["Enter the dungeon apprentice! Bring back the Talisman!".to_owned()],
-    });
+    world.insert(GameLog::new(copy::LOG_INTRO));
+    world.insert(Identification::new());
+    world.fetch_mut::<Viewport>().reset();
 }
 
 pub fn get_world() -> World {
@@ -117,6 +132,7 @@ pub fn get_world() -> World {
     world.register::<Container>();
     world.register::<Flammable>();
     world.register::<OnFire>();
+    world.register::<Regeneration>();
     world.register::<CausesFire>();
     world.register::<WantsToSearchHidden>();
     world.register::<Trap>();
@@ -151,16 +167,29 @@ pub fn get_world() -> World {
     world.register::<Armable>();
     world.register::<DamageHistory>();
     world.register::<Inventory>();
+    world.register::<Hunger>();
+    world.register::<Edible>();
+    world.register::<Cookable>();
+    world.register::<Rotting>();
+    world.register::<CookingSpot>();
+    world.register::<WantsToEat>();
+    world.register::<WantsToCook>();
+    world.register::<Bulk>();
+    world.register::<Capacity>();
+    world.register::<Stackable>();
+    world.register::<Identifiable>();
+    world.register::<Identifies>();
     world.insert(SimpleMarkerAllocator::<Saveable>::new());
-    world.insert(GameLog {
-        entries: vec!["Enter the dungeon apprentice! Bring back the Talisman!".to_owned()],
-    }); // This needs to get moved to a continue game function I think...
+    world.insert(GameLog::new(copy::LOG_INTRO)); // This needs to get moved to a continue game function I think...
     world.insert(RandomNumberGenerator::new());
     world.insert(ParticleEffectSpawner::new());
+    world.insert(SoundEffectQueue::new());
     world.insert(BloodSpawner::new());
     world.insert(DebrisSpawner::new());
     world.insert(TrapSpawner::new());
     world.insert(ItemSpawner::new());
     world.insert(CorpseSpawner::new());
+    world.insert(Identification::new());
+    world.insert(Viewport::new(DEFAULT_VIEWPORT_MARGIN, false));
     world
 }